@@ -0,0 +1,34 @@
+use std::borrow::Cow;
+
+use anyhow::Result;
+use gpui::{AssetSource, SharedString};
+use gpui_component_assets::Assets as ComponentAssets;
+use rust_embed::RustEmbed;
+
+/// Gallery assets layered over the gpui-component icon set.
+#[derive(RustEmbed)]
+#[folder = "assets"]
+#[include = "docs/**/*"]
+pub struct Assets;
+
+impl AssetSource for Assets {
+    fn load(&self, path: &str) -> Result<Option<Cow<'static, [u8]>>> {
+        if path.is_empty() {
+            return Ok(None);
+        }
+
+        match Self::get(path) {
+            Some(file) => Ok(Some(file.data)),
+            None => ComponentAssets.load(path),
+        }
+    }
+
+    fn list(&self, path: &str) -> Result<Vec<SharedString>> {
+        let mut items = Self::iter()
+            .filter(|p| p.starts_with(path))
+            .map(|p| SharedString::from(p.to_string()))
+            .collect::<Vec<_>>();
+        items.extend(ComponentAssets.list(path)?);
+        Ok(items)
+    }
+}
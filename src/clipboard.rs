@@ -0,0 +1,174 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+
+use gpui::{App, ClipboardItem, Global, SharedString, Window};
+
+/// How many recent copies the history keeps.
+const HISTORY_LIMIT: usize = 10;
+
+/// Where copied text actually goes. The gallery talks to this instead of the
+/// platform directly, so it can run against an in-memory clipboard off-device.
+pub trait ClipboardBackend {
+    fn read(&self, cx: &App) -> Option<String>;
+    fn write(&self, text: &str, cx: &mut App);
+}
+
+/// The system clipboard, through gpui.
+pub struct PlatformClipboard;
+
+impl ClipboardBackend for PlatformClipboard {
+    fn read(&self, cx: &App) -> Option<String> {
+        cx.read_from_clipboard()?.text()
+    }
+
+    fn write(&self, text: &str, cx: &mut App) {
+        cx.write_to_clipboard(ClipboardItem::new_string(text.to_string()));
+    }
+}
+
+/// A clipboard that lives only in this process, for Linux test runs where
+/// there is no display server to own the selection.
+#[cfg(test)]
+#[derive(Default)]
+pub struct MemoryClipboard {
+    contents: RefCell<Option<String>>,
+}
+
+#[cfg(test)]
+impl ClipboardBackend for MemoryClipboard {
+    fn read(&self, _: &App) -> Option<String> {
+        self.contents.borrow().clone()
+    }
+
+    fn write(&self, text: &str, _: &mut App) {
+        *self.contents.borrow_mut() = Some(text.to_string());
+    }
+}
+
+/// The clipboard backend plus a ring buffer of recent copies, newest first.
+pub struct ClipboardService {
+    backend: Box<dyn ClipboardBackend>,
+    history: VecDeque<SharedString>,
+}
+
+impl Global for ClipboardService {}
+
+impl ClipboardService {
+    pub fn new(backend: impl ClipboardBackend + 'static) -> Self {
+        Self {
+            backend: Box::new(backend),
+            history: VecDeque::with_capacity(HISTORY_LIMIT),
+        }
+    }
+
+    /// Adds `text` to the front of the history. Copying something already in
+    /// the history moves it to the front instead of duplicating it.
+    fn record(&mut self, text: SharedString) {
+        if text.is_empty() {
+            return;
+        }
+        self.history.retain(|entry| entry != &text);
+        if self.history.len() == HISTORY_LIMIT {
+            self.history.pop_back();
+        }
+        self.history.push_front(text);
+    }
+}
+
+pub fn init(backend: impl ClipboardBackend + 'static, cx: &mut App) {
+    cx.set_global(ClipboardService::new(backend));
+}
+
+/// Writes `text` to the clipboard and records it in the history. Text that
+/// gpui-component's `Clipboard` button already copied goes through here too,
+/// so the backend and the history never disagree.
+pub fn copy(text: impl Into<SharedString>, cx: &mut App) {
+    let text = text.into();
+    cx.update_global::<ClipboardService, _>(|service, cx| {
+        service.backend.write(&text, cx);
+        service.record(text);
+    });
+}
+
+/// Records what an input just copied or cut. Inputs write to the clipboard
+/// themselves, so this reads it back once their handler has run.
+pub fn record_input_copy(window: &mut Window, cx: &mut App) {
+    window.defer(cx, |_, cx| {
+        if let Some(text) = paste(cx) {
+            cx.global_mut::<ClipboardService>().record(text);
+        }
+    });
+}
+
+/// The current clipboard text, if any.
+pub fn paste(cx: &App) -> Option<SharedString> {
+    let service = cx.global::<ClipboardService>();
+    service
+        .backend
+        .read(cx)
+        .filter(|text| !text.is_empty())
+        .map(SharedString::from)
+}
+
+/// Recent copies, newest first.
+pub fn history(cx: &App) -> Vec<SharedString> {
+    cx.global::<ClipboardService>()
+        .history
+        .iter()
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::*;
+
+    fn recorded(texts: &[&str]) -> Vec<SharedString> {
+        let mut service = ClipboardService::new(MemoryClipboard::default());
+        for text in texts {
+            service.record(SharedString::from(text.to_string()));
+        }
+        service.history.into_iter().collect()
+    }
+
+    #[test]
+    fn history_is_newest_first_and_skips_empty_text() {
+        assert_eq!(recorded(&["a", "", "b"]), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn copying_an_entry_again_moves_it_to_the_front() {
+        assert_eq!(recorded(&["a", "b", "c", "a"]), vec!["a", "c", "b"]);
+    }
+
+    #[test]
+    fn history_drops_the_oldest_entry_past_the_limit() {
+        let texts = (0..=HISTORY_LIMIT)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+        let history = recorded(&texts.iter().map(String::as_str).collect::<Vec<_>>());
+
+        assert_eq!(history.len(), HISTORY_LIMIT);
+        assert_eq!(history[0], HISTORY_LIMIT.to_string());
+        assert!(!history.iter().any(|text| text == "0"));
+    }
+
+    #[gpui::test]
+    fn copy_then_paste_round_trips_through_the_backend(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            init(MemoryClipboard::default(), cx);
+            assert_eq!(paste(cx), None);
+
+            copy("first", cx);
+            copy("second", cx);
+            assert_eq!(paste(cx), Some("second".into()));
+            assert_eq!(history(cx), vec!["second", "first"]);
+
+            copy("", cx);
+            assert_eq!(paste(cx), None);
+            assert_eq!(history(cx), vec!["second", "first"]);
+        });
+    }
+}
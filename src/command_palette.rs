@@ -0,0 +1,485 @@
+use gpui::{
+    actions, div, prelude::*, px, App, Context, Entity, EventEmitter, FocusHandle, Focusable,
+    KeyDownEvent, Keystroke, ScrollHandle, SharedString, Subscription, Window,
+};
+use gpui_component::{
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    kbd::Kbd,
+    v_flex, ActiveTheme as _, IconName, Sizable, Theme, ThemeMode, WindowExt as _,
+};
+
+use crate::i18n::{t, t_args};
+use crate::keymap;
+use crate::router::Route;
+use crate::sections::{self, Section};
+use crate::ComponentGallery;
+
+actions!(
+    gallery,
+    [
+        ToggleCommandPalette,
+        ToggleTheme,
+        OpenDialog,
+        OpenSheet,
+        PushNotification,
+        ResetState
+    ]
+);
+
+/// How many recently run commands are remembered.
+const RECENT_LIMIT: usize = 5;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    JumpTo(Section),
+    ToggleTheme,
+    OpenDialog,
+    OpenSheet,
+    PushNotification,
+    ResetState,
+    ShowKeyboardShortcuts,
+}
+
+impl Command {
+    pub fn all() -> Vec<Command> {
+        let mut commands = vec![
+            Command::ToggleTheme,
+            Command::OpenDialog,
+            Command::OpenSheet,
+            Command::PushNotification,
+            Command::ResetState,
+            Command::ShowKeyboardShortcuts,
+        ];
+        commands.extend(Section::ALL.iter().map(|section| Command::JumpTo(*section)));
+        commands
+    }
+
+    pub fn label(self, cx: &App) -> SharedString {
+        match self {
+            Command::JumpTo(section) => t_args(
+                cx,
+                "palette.jump_to",
+                &[("section", &t(cx, section.title_key()))],
+            ),
+            Command::ToggleTheme => t(cx, "palette.toggle_theme"),
+            Command::OpenDialog => t(cx, "buttons.open_dialog"),
+            Command::OpenSheet => t(cx, "buttons.open_sheet"),
+            Command::PushNotification => t(cx, "palette.push_notification"),
+            Command::ResetState => t(cx, "palette.reset_state"),
+            Command::ShowKeyboardShortcuts => t(cx, "keymap.show_keyboard_shortcuts"),
+        }
+    }
+
+    /// The name of the action bound to this command in the keymap, if any.
+    fn action_name(self) -> Option<&'static str> {
+        match self {
+            Command::JumpTo(_) => None,
+            Command::ToggleTheme => Some("ToggleTheme"),
+            Command::OpenDialog => Some("OpenDialog"),
+            Command::OpenSheet => Some("OpenSheet"),
+            Command::PushNotification => Some("PushNotification"),
+            Command::ResetState => Some("ResetState"),
+            Command::ShowKeyboardShortcuts => Some("ShowKeyboardShortcuts"),
+        }
+    }
+
+    pub fn keystroke(self, cx: &App) -> Option<Keystroke> {
+        keymap::keystroke_for(self.action_name()?, cx)
+    }
+}
+
+/// Scores `text` against a fuzzy `query`: every query character must appear in order.
+/// Consecutive matches and matches at word starts score higher.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<usize> {
+    let mut score = 0;
+    let mut query = query.chars().flat_map(char::to_lowercase).peekable();
+    let mut prev_matched = false;
+    let mut prev_char = ' ';
+    for c in text.chars().flat_map(char::to_lowercase) {
+        let Some(q) = query.peek() else {
+            break;
+        };
+        if *q == c {
+            score += 1;
+            if prev_matched {
+                score += 2;
+            }
+            if !prev_char.is_alphanumeric() {
+                score += 3;
+            }
+            prev_matched = true;
+            query.next();
+        } else {
+            prev_matched = false;
+        }
+        prev_char = c;
+    }
+    query.peek().is_none().then_some(score)
+}
+
+/// The commands whose label matches `query`. Recently run commands come
+/// first, most recent on top; the rest follow by score.
+fn rank_matches(
+    query: &str,
+    commands: impl IntoIterator<Item = (Command, SharedString)>,
+    recent: &[Command],
+) -> Vec<Command> {
+    let recent_rank = |command: &Command| {
+        recent
+            .iter()
+            .position(|recent| recent == command)
+            .unwrap_or(usize::MAX)
+    };
+    let mut matches = commands
+        .into_iter()
+        .filter_map(|(command, label)| fuzzy_score(query, &label).map(|score| (command, score)))
+        .collect::<Vec<_>>();
+    matches.sort_by_key(|(command, score)| (recent_rank(command), std::cmp::Reverse(*score)));
+    matches.into_iter().map(|(command, _)| command).collect()
+}
+
+/// Moves `command` to the front of the recent list, dropping the oldest past
+/// [`RECENT_LIMIT`].
+fn remember(recent: &mut Vec<Command>, command: Command) {
+    recent.retain(|recent| *recent != command);
+    recent.insert(0, command);
+    recent.truncate(RECENT_LIMIT);
+}
+
+pub enum PaletteEvent {
+    Confirm(Command),
+}
+
+pub struct CommandPalette {
+    query: Entity<InputState>,
+    recent: Vec<Command>,
+    matches: Vec<Command>,
+    selected: usize,
+    scroll: ScrollHandle,
+    _subscription: Subscription,
+}
+
+impl EventEmitter<PaletteEvent> for CommandPalette {}
+
+impl CommandPalette {
+    pub fn new(recent: Vec<Command>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let query =
+            cx.new(|cx| InputState::new(window, cx).placeholder(t(cx, "palette.placeholder")));
+        let _subscription = cx.subscribe(&query, |this, _, ev: &InputEvent, cx| match ev {
+            InputEvent::Change => this.update_matches(cx),
+            InputEvent::PressEnter { .. } => this.confirm(cx),
+            _ => {}
+        });
+
+        let mut this = Self {
+            query,
+            recent,
+            matches: Vec::new(),
+            selected: 0,
+            scroll: ScrollHandle::new(),
+            _subscription,
+        };
+        this.update_matches(cx);
+        this
+    }
+
+    fn update_matches(&mut self, cx: &mut Context<Self>) {
+        let query = self.query.read(cx).value();
+        let commands = Command::all()
+            .into_iter()
+            .map(|command| (command, command.label(cx)));
+        self.matches = rank_matches(&query, commands, &self.recent);
+        self.selected = 0;
+        self.scroll.scroll_to_item(0);
+        cx.notify();
+    }
+
+    fn select(&mut self, delta: isize, cx: &mut Context<Self>) {
+        if self.matches.is_empty() {
+            return;
+        }
+        let len = self.matches.len() as isize;
+        self.selected = ((self.selected as isize + delta).rem_euclid(len)) as usize;
+        self.scroll.scroll_to_item(self.selected);
+        cx.notify();
+    }
+
+    fn confirm(&mut self, cx: &mut Context<Self>) {
+        if let Some(command) = self.matches.get(self.selected) {
+            cx.emit(PaletteEvent::Confirm(*command));
+        }
+    }
+}
+
+impl Focusable for CommandPalette {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.query.focus_handle(cx)
+    }
+}
+
+impl Render for CommandPalette {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let recent_count = self.recent.len();
+        let query_is_empty = self.query.read(cx).value().is_empty();
+
+        v_flex()
+            .gap_2()
+            .capture_key_down(cx.listener(|this, ev: &KeyDownEvent, _, cx| {
+                match ev.keystroke.key.as_str() {
+                    "up" => this.select(-1, cx),
+                    "down" => this.select(1, cx),
+                    _ => return,
+                }
+                cx.stop_propagation();
+            }))
+            .child(Input::new(&self.query).prefix(IconName::Search))
+            .child(
+                v_flex()
+                    .id("command-palette-matches")
+                    .max_h(px(320.))
+                    .track_scroll(&self.scroll)
+                    .overflow_y_scroll()
+                    .when(self.matches.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .p_2()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(t(cx, "palette.empty")),
+                        )
+                    })
+                    .children(self.matches.iter().enumerate().map(|(ix, command)| {
+                        let is_recent = query_is_empty && ix < recent_count;
+                        let command = *command;
+                        h_flex()
+                            .id(("command", ix))
+                            .gap_2()
+                            .px_2()
+                            .py_1()
+                            .rounded(cx.theme().radius)
+                            .justify_between()
+                            .when(ix == self.selected, |this| this.bg(cx.theme().accent))
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.selected = ix;
+                                this.confirm(cx);
+                            }))
+                            .child(h_flex().gap_2().child(command.label(cx)).when(
+                                is_recent,
+                                |this| {
+                                    this.child(
+                                        div()
+                                            .text_xs()
+                                            .text_color(cx.theme().muted_foreground)
+                                            .child(t(cx, "palette.recent")),
+                                    )
+                                },
+                            ))
+                            .children(
+                                command
+                                    .keystroke(cx)
+                                    .map(|keystroke| Kbd::new(keystroke).small()),
+                            )
+                    })),
+            )
+    }
+}
+
+impl ComponentGallery {
+    pub(crate) fn toggle_command_palette(
+        &mut self,
+        _: &ToggleCommandPalette,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.is_command_palette_open(window, cx) {
+            window.close_dialog(cx);
+            return;
+        }
+        self.open_command_palette(window, cx);
+    }
+
+    /// Whether the topmost dialog is the palette. It keeps focus while open,
+    /// so a dialog opened over it takes focus away.
+    fn is_command_palette_open(&self, window: &Window, cx: &App) -> bool {
+        window.has_active_dialog(cx)
+            && self
+                .palette
+                .upgrade()
+                .is_some_and(|palette| palette.focus_handle(cx).contains_focused(window, cx))
+    }
+
+    pub(crate) fn open_command_palette(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let palette = cx.new(|cx| CommandPalette::new(self.recent_commands.clone(), window, cx));
+        self.palette = palette.downgrade();
+        self.palette_subscription = Some(cx.subscribe_in(
+            &palette,
+            window,
+            |this, _, ev: &PaletteEvent, window, cx| {
+                let PaletteEvent::Confirm(command) = ev;
+                window.close_dialog(cx);
+                this.run_command(*command, window, cx);
+            },
+        ));
+
+        window.open_dialog(cx, {
+            let palette = palette.clone();
+            move |dialog, _, cx| {
+                dialog
+                    .title(t(cx, "palette.title"))
+                    .width(px(480.))
+                    .child(palette.clone())
+            }
+        });
+        palette.focus_handle(cx).focus(window);
+    }
+
+    pub(crate) fn run_command(
+        &mut self,
+        command: Command,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        remember(&mut self.recent_commands, command);
+
+        match command {
+            Command::JumpTo(section) => self.navigate(Route::section(section), cx),
+            Command::ToggleTheme => {
+                let mode = if cx.theme().mode.is_dark() {
+                    ThemeMode::Light
+                } else {
+                    ThemeMode::Dark
+                };
+                Theme::change(mode, Some(window), cx);
+            }
+            Command::OpenDialog => sections::buttons::open_dialog(window, cx),
+            Command::OpenSheet => sections::buttons::open_sheet(window, cx),
+            Command::PushNotification => sections::buttons::notify(window, cx),
+            Command::ResetState => self.confirm_reset(window, cx),
+            Command::ShowKeyboardShortcuts => keymap::open_shortcuts_sheet(window, cx),
+        }
+        cx.notify();
+    }
+
+    pub(crate) fn toggle_theme(
+        &mut self,
+        _: &ToggleTheme,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.run_command(Command::ToggleTheme, window, cx);
+    }
+
+    pub(crate) fn open_dialog(
+        &mut self,
+        _: &OpenDialog,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.run_command(Command::OpenDialog, window, cx);
+    }
+
+    pub(crate) fn open_sheet(
+        &mut self,
+        _: &OpenSheet,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.run_command(Command::OpenSheet, window, cx);
+    }
+
+    pub(crate) fn push_notification(
+        &mut self,
+        _: &PushNotification,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.run_command(Command::PushNotification, window, cx);
+    }
+
+    pub(crate) fn reset(&mut self, _: &ResetState, window: &mut Window, cx: &mut Context<Self>) {
+        self.run_command(Command::ResetState, window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn commands() -> Vec<(Command, SharedString)> {
+        vec![
+            (Command::ToggleTheme, "Toggle theme".into()),
+            (Command::OpenDialog, "Open dialog".into()),
+            (Command::OpenSheet, "Open sheet".into()),
+            (Command::PushNotification, "Push notification".into()),
+            (Command::ResetState, "Reset state".into()),
+        ]
+    }
+
+    #[test]
+    fn queries_match_subsequences_only() {
+        assert!(fuzzy_score("tgth", "Toggle theme").is_some());
+        assert!(fuzzy_score("TOGGLE", "Toggle theme").is_some());
+        assert_eq!(fuzzy_score("themet", "Toggle theme"), None);
+        assert_eq!(fuzzy_score("xyz", "Toggle theme"), None);
+        assert_eq!(fuzzy_score("", "Toggle theme"), Some(0));
+    }
+
+    #[test]
+    fn prefix_and_contiguous_matches_score_higher() {
+        // At a word start rather than inside a word.
+        assert!(fuzzy_score("dia", "dialog") > fuzzy_score("dia", "media"));
+        // Consecutive rather than spread out.
+        assert!(fuzzy_score("ope", "open") > fuzzy_score("ope", "oxpxe"));
+    }
+
+    #[test]
+    fn matches_are_ranked_by_score() {
+        assert_eq!(
+            rank_matches("sheet", commands(), &[]),
+            vec![Command::OpenSheet]
+        );
+        // Word starts first; ties keep the commands' own order.
+        assert_eq!(
+            rank_matches("o", commands(), &[]),
+            vec![
+                Command::OpenDialog,
+                Command::OpenSheet,
+                Command::ToggleTheme,
+                Command::PushNotification,
+            ]
+        );
+    }
+
+    #[test]
+    fn an_empty_query_lists_recent_commands_first() {
+        let recent = [Command::ResetState, Command::OpenDialog];
+        assert_eq!(
+            rank_matches("", commands(), &recent),
+            vec![
+                Command::ResetState,
+                Command::OpenDialog,
+                Command::ToggleTheme,
+                Command::OpenSheet,
+                Command::PushNotification,
+            ]
+        );
+    }
+
+    #[test]
+    fn running_a_command_again_moves_it_to_the_front() {
+        let mut recent = Vec::new();
+        remember(&mut recent, Command::OpenDialog);
+        remember(&mut recent, Command::ToggleTheme);
+        remember(&mut recent, Command::OpenDialog);
+        assert_eq!(recent, vec![Command::OpenDialog, Command::ToggleTheme]);
+
+        for command in commands().into_iter().map(|(command, _)| command) {
+            remember(&mut recent, command);
+        }
+        remember(&mut recent, Command::ShowKeyboardShortcuts);
+        assert_eq!(recent.len(), RECENT_LIMIT);
+        assert_eq!(recent[0], Command::ShowKeyboardShortcuts);
+        assert!(!recent.contains(&Command::ToggleTheme));
+    }
+}
@@ -0,0 +1,162 @@
+use std::cell::RefCell;
+use std::future::Future;
+use std::rc::Rc;
+
+use futures::channel::oneshot;
+use gpui::{prelude::*, App, Focusable, SharedString, Window};
+use gpui_component::{
+    dialog::DialogButtonProps,
+    input::{Input, InputState},
+    v_flex, WindowExt as _,
+};
+
+use crate::i18n::t;
+
+/// A one-shot result shared between a dialog's OK, Cancel and close handlers.
+/// Whichever fires first resolves the future; the rest are ignored.
+struct Reply<T>(Rc<RefCell<Option<oneshot::Sender<Option<T>>>>>);
+
+impl<T> Clone for Reply<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: 'static> Reply<T> {
+    fn new() -> (Self, impl Future<Output = Option<T>>) {
+        let (tx, rx) = oneshot::channel();
+        let reply = Self(Rc::new(RefCell::new(Some(tx))));
+        (reply, async move { rx.await.ok().flatten() })
+    }
+
+    fn send(&self, value: Option<T>) {
+        if let Some(tx) = self.0.borrow_mut().take() {
+            tx.send(value).ok();
+        }
+    }
+}
+
+/// Asks the user to confirm an action. Resolves to `Some(())` on OK and to
+/// `None` on Cancel or when the dialog is dismissed from the backdrop.
+pub fn confirm(
+    title: impl Into<SharedString>,
+    message: impl Into<SharedString>,
+    window: &mut Window,
+    cx: &mut App,
+) -> impl Future<Output = Option<()>> {
+    let (title, message) = (title.into(), message.into());
+    let (reply, result) = Reply::new();
+
+    window.defer(cx, move |window, cx| {
+        window.open_dialog(cx, move |dialog, _, cx| {
+            dialog
+                .title(title.clone())
+                .child(message.clone())
+                .confirm()
+                .button_props(
+                    DialogButtonProps::default()
+                        .ok_text(t(cx, "common.ok"))
+                        .cancel_text(t(cx, "common.cancel")),
+                )
+                .on_ok({
+                    let reply = reply.clone();
+                    move |_, _, _| {
+                        reply.send(Some(()));
+                        true
+                    }
+                })
+                .on_close({
+                    let reply = reply.clone();
+                    move |_, _, _| reply.send(None)
+                })
+        });
+    });
+
+    result
+}
+
+/// Asks the user for a line of text, pre-filled with `default`. Resolves to the
+/// entered text on OK and to `None` on Cancel or backdrop dismissal.
+pub fn prompt(
+    title: impl Into<SharedString>,
+    default: impl Into<SharedString>,
+    window: &mut Window,
+    cx: &mut App,
+) -> impl Future<Output = Option<SharedString>> {
+    let (title, default) = (title.into(), default.into());
+    let (reply, result) = Reply::new();
+
+    window.defer(cx, move |window, cx| {
+        let input = cx.new(|cx| InputState::new(window, cx).default_value(default));
+        window.open_dialog(cx, {
+            let input = input.clone();
+            move |dialog, _, cx| {
+                dialog
+                    .title(title.clone())
+                    .child(v_flex().child(Input::new(&input)))
+                    .confirm()
+                    .button_props(
+                        DialogButtonProps::default()
+                            .ok_text(t(cx, "common.ok"))
+                            .cancel_text(t(cx, "common.cancel")),
+                    )
+                    .on_ok({
+                        let (reply, input) = (reply.clone(), input.clone());
+                        move |_, _, cx| {
+                            reply.send(Some(input.read(cx).value()));
+                            true
+                        }
+                    })
+                    .on_close({
+                        let reply = reply.clone();
+                        move |_, _, _| reply.send(None)
+                    })
+            }
+        });
+        input.focus_handle(cx).focus(window);
+    });
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+
+    use super::*;
+
+    #[test]
+    fn the_first_answer_wins() {
+        let (reply, result) = Reply::new();
+        reply.send(Some("first"));
+        reply.send(Some("second"));
+        assert_eq!(block_on(result), Some("first"));
+    }
+
+    #[test]
+    fn closing_after_ok_keeps_the_ok_value() {
+        let (reply, result) = Reply::new();
+        let (on_ok, on_close) = (reply.clone(), reply);
+        on_ok.send(Some(()));
+        // The dialog closes after OK, which runs the close handler too.
+        on_close.send(None);
+        assert_eq!(block_on(result), Some(()));
+    }
+
+    #[test]
+    fn cancelling_resolves_to_none() {
+        let (reply, result) = Reply::<()>::new();
+        reply.send(None);
+        reply.send(Some(()));
+        assert_eq!(block_on(result), None);
+    }
+
+    #[test]
+    fn a_dialog_dropped_without_an_answer_resolves_to_none() {
+        let (reply, result) = Reply::<()>::new();
+        let handler = reply.clone();
+        drop(reply);
+        drop(handler);
+        assert_eq!(block_on(result), None);
+    }
+}
@@ -0,0 +1,728 @@
+use std::collections::HashMap;
+
+use gpui::{
+    actions, div, prelude::*, px, transparent_black, App, Context, Div, FocusHandle, Focusable,
+    KeyDownEvent, Window,
+};
+use gpui_component::ActiveTheme as _;
+
+use crate::history::{Change, Redo, Undo};
+use crate::keymap::{ToggleSidebar, ToggleSidebarSide};
+use crate::router::{Demo, Route};
+use crate::sections::Section;
+use crate::ComponentGallery;
+
+actions!(gallery, [FocusNext, FocusPrevious]);
+
+pub const CONTEXT: &str = "ComponentGallery";
+
+/// Every keyboard-reachable control, in Tab order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FocusTarget {
+    Sidebar,
+    NavMenu,
+    Search,
+    Language,
+    Undo,
+    Redo,
+    Notifications,
+    Shortcuts,
+    RouteBack,
+    TodoInput,
+    Primary,
+    Secondary,
+    Outline,
+    Ghost,
+    ButtonGroup,
+    Notify,
+    OpenDialog,
+    OpenSheet,
+    ConfirmReset,
+    PromptName,
+    Textarea,
+    Select,
+    Agree,
+    Enable,
+    Radio,
+    ColorPicker,
+    FormName,
+    FormEmail,
+    FormRole,
+    FormSubscribe,
+    FormNotes,
+    JobDownload,
+    JobHash,
+    JobUpload,
+    Slider,
+    SliderInput,
+    Rating,
+    HalfRating,
+    Stepper,
+    WizardBack,
+    WizardNext,
+    Tabs,
+    OpenFormsPage,
+    OpenTodo,
+    AccordionMultiple,
+    ExpandAll,
+    CollapseAll,
+    Collapsible,
+    Tooltip,
+    Popover,
+    HoverCard,
+    CommandPalette,
+    DocsLink,
+    SheetLeft,
+    SheetRight,
+    SheetTop,
+    SheetBottom,
+    MarkdownTabs,
+    MarkdownSource,
+    DocsBack,
+    DocsHome,
+    DropdownMenu,
+    ReloadData,
+    List,
+    DatePicker,
+    Table,
+    Pagination,
+    Tree,
+    ToggleBottomPanel,
+    ResetPanels,
+    ToggleSide,
+    ToggleCollapse,
+}
+
+impl FocusTarget {
+    pub const ORDER: &'static [FocusTarget] = &[
+        FocusTarget::Sidebar,
+        FocusTarget::NavMenu,
+        FocusTarget::Search,
+        FocusTarget::Language,
+        FocusTarget::Undo,
+        FocusTarget::Redo,
+        FocusTarget::Notifications,
+        FocusTarget::Shortcuts,
+        FocusTarget::RouteBack,
+        FocusTarget::TodoInput,
+        FocusTarget::Primary,
+        FocusTarget::Secondary,
+        FocusTarget::Outline,
+        FocusTarget::Ghost,
+        FocusTarget::ButtonGroup,
+        FocusTarget::Notify,
+        FocusTarget::OpenDialog,
+        FocusTarget::OpenSheet,
+        FocusTarget::ConfirmReset,
+        FocusTarget::PromptName,
+        FocusTarget::Textarea,
+        FocusTarget::Select,
+        FocusTarget::Agree,
+        FocusTarget::Enable,
+        FocusTarget::Radio,
+        FocusTarget::ColorPicker,
+        FocusTarget::FormName,
+        FocusTarget::FormEmail,
+        FocusTarget::FormRole,
+        FocusTarget::FormSubscribe,
+        FocusTarget::FormNotes,
+        FocusTarget::JobDownload,
+        FocusTarget::JobHash,
+        FocusTarget::JobUpload,
+        FocusTarget::Slider,
+        FocusTarget::SliderInput,
+        FocusTarget::Rating,
+        FocusTarget::HalfRating,
+        FocusTarget::Stepper,
+        FocusTarget::WizardBack,
+        FocusTarget::WizardNext,
+        FocusTarget::Tabs,
+        FocusTarget::OpenFormsPage,
+        FocusTarget::OpenTodo,
+        FocusTarget::AccordionMultiple,
+        FocusTarget::ExpandAll,
+        FocusTarget::CollapseAll,
+        FocusTarget::Collapsible,
+        FocusTarget::Tooltip,
+        FocusTarget::Popover,
+        FocusTarget::HoverCard,
+        FocusTarget::CommandPalette,
+        FocusTarget::DocsLink,
+        FocusTarget::SheetLeft,
+        FocusTarget::SheetRight,
+        FocusTarget::SheetTop,
+        FocusTarget::SheetBottom,
+        FocusTarget::MarkdownTabs,
+        FocusTarget::MarkdownSource,
+        FocusTarget::DocsBack,
+        FocusTarget::DocsHome,
+        FocusTarget::DropdownMenu,
+        FocusTarget::ReloadData,
+        FocusTarget::List,
+        FocusTarget::DatePicker,
+        FocusTarget::Table,
+        FocusTarget::Pagination,
+        FocusTarget::Tree,
+        FocusTarget::ToggleBottomPanel,
+        FocusTarget::ResetPanels,
+        FocusTarget::ToggleSide,
+        FocusTarget::ToggleCollapse,
+    ];
+
+    /// Targets whose focus handle belongs to a gpui-component entity rather than a ring.
+    fn is_entity(self) -> bool {
+        matches!(
+            self,
+            FocusTarget::Search
+                | FocusTarget::Language
+                | FocusTarget::TodoInput
+                | FocusTarget::Textarea
+                | FocusTarget::Select
+                | FocusTarget::DatePicker
+                | FocusTarget::ColorPicker
+                | FocusTarget::FormName
+                | FocusTarget::FormEmail
+                | FocusTarget::FormRole
+                | FocusTarget::FormNotes
+                | FocusTarget::SliderInput
+                | FocusTarget::MarkdownSource
+                | FocusTarget::List
+                | FocusTarget::Table
+                | FocusTarget::Tree
+        )
+    }
+
+    /// The section the target is drawn in, or `None` for the route's own controls.
+    fn section(self) -> Option<Section> {
+        use FocusTarget::*;
+        Some(match self {
+            RouteBack | TodoInput => return None,
+            Sidebar | NavMenu | Search | Language | Undo | Redo | Notifications | Shortcuts => {
+                Section::Header
+            }
+            Primary | Secondary | Outline | Ghost | ButtonGroup | Notify | OpenDialog
+            | OpenSheet | ConfirmReset | PromptName => Section::Buttons,
+            Textarea | Select | Agree | Enable | Radio | ColorPicker | FormName | FormEmail
+            | FormRole | FormSubscribe | FormNotes => Section::Forms,
+            JobDownload | JobHash | JobUpload => Section::Feedback,
+            Slider | SliderInput | Rating | HalfRating | Stepper | WizardBack | WizardNext => {
+                Section::Controls
+            }
+            Tabs | OpenFormsPage | OpenTodo => Section::Navigation,
+            AccordionMultiple | ExpandAll | CollapseAll | Collapsible => Section::Disclosure,
+            Tooltip | Popover | HoverCard | CommandPalette | DocsLink => Section::Overlays,
+            SheetLeft | SheetRight | SheetTop | SheetBottom => Section::Sheets,
+            MarkdownTabs | MarkdownSource => Section::Markdown,
+            DocsBack | DocsHome => Section::Docs,
+            DropdownMenu | ReloadData | List | DatePicker | Table | Pagination | Tree
+            | ToggleBottomPanel | ResetPanels | ToggleSide | ToggleCollapse => Section::Advanced,
+        })
+    }
+
+    /// Whether the target is part of the element tree at the given layout and route.
+    pub fn is_rendered(self, is_compact: bool, route: Route) -> bool {
+        match self {
+            FocusTarget::NavMenu | FocusTarget::MarkdownTabs if !is_compact => false,
+            // Compact layouts swap the docked sidebar for the menu's drawer.
+            FocusTarget::Sidebar => !is_compact,
+            FocusTarget::RouteBack => route != Route::Home,
+            FocusTarget::TodoInput => route == Route::Demo(Demo::Todo),
+            target => target.section().is_none_or(|section| route.shows(section)),
+        }
+    }
+}
+
+/// Returns the target after `current` in [`FocusTarget::ORDER`], wrapping around and
+/// skipping targets that are not rendered at the current layout and route.
+pub fn next_target(
+    current: Option<FocusTarget>,
+    reverse: bool,
+    is_compact: bool,
+    route: Route,
+) -> FocusTarget {
+    let order = FocusTarget::ORDER
+        .iter()
+        .copied()
+        .filter(|target| target.is_rendered(is_compact, route))
+        .collect::<Vec<_>>();
+    let len = order.len();
+    let ix = match current.and_then(|current| order.iter().position(|t| *t == current)) {
+        Some(ix) if reverse => (ix + len - 1) % len,
+        Some(ix) => (ix + 1) % len,
+        None if reverse => len - 1,
+        None => 0,
+    };
+    order[ix]
+}
+
+/// Focus handles for the controls that are not focusable on their own.
+pub struct FocusRings {
+    handles: HashMap<FocusTarget, FocusHandle>,
+}
+
+impl FocusRings {
+    pub fn new(cx: &mut App) -> Self {
+        let handles = FocusTarget::ORDER
+            .iter()
+            .enumerate()
+            .filter(|(_, target)| !target.is_entity())
+            .map(|(ix, target)| {
+                let handle = cx.focus_handle().tab_index(ix as isize).tab_stop(true);
+                (*target, handle)
+            })
+            .collect();
+        Self { handles }
+    }
+
+    pub fn handle(&self, target: FocusTarget) -> &FocusHandle {
+        &self.handles[&target]
+    }
+}
+
+impl ComponentGallery {
+    fn target_handle(&self, target: FocusTarget, cx: &App) -> FocusHandle {
+        match target {
+            FocusTarget::Search => self.input_state.focus_handle(cx),
+            FocusTarget::TodoInput => self.todo.input.focus_handle(cx),
+            FocusTarget::Language => self.language_select.focus_handle(cx),
+            FocusTarget::Textarea => self.textarea_state.focus_handle(cx),
+            FocusTarget::Select => self.select_state.focus_handle(cx),
+            FocusTarget::DatePicker => self.date_picker.focus_handle(cx),
+            FocusTarget::ColorPicker => self.color_picker.focus_handle(cx),
+            FocusTarget::FormName => self.form_name.focus_handle(cx),
+            FocusTarget::FormEmail => self.form_email.focus_handle(cx),
+            FocusTarget::FormRole => self.form_role.focus_handle(cx),
+            FocusTarget::FormNotes => self.form_notes.focus_handle(cx),
+            FocusTarget::SliderInput => self.slider_input.focus_handle(cx),
+            FocusTarget::MarkdownSource => self.markdown_source.focus_handle(cx),
+            FocusTarget::List => self.list_state.focus_handle(cx),
+            FocusTarget::Table => self.table_state.focus_handle(cx),
+            FocusTarget::Tree => self.tree_state.focus_handle(cx),
+            target => self.focus_rings.handle(target).clone(),
+        }
+    }
+
+    fn focused_target(&self, window: &Window, cx: &App) -> Option<FocusTarget> {
+        FocusTarget::ORDER
+            .iter()
+            .copied()
+            .find(|target| self.target_handle(*target, cx).contains_focused(window, cx))
+    }
+
+    fn move_focus(&mut self, reverse: bool, window: &mut Window, cx: &mut Context<Self>) {
+        let is_compact = window.bounds().size.width <= px(680.);
+        let current = self.focused_target(window, cx);
+        let target = next_target(current, reverse, is_compact, self.router.current());
+        self.target_handle(target, cx).focus(window);
+        cx.notify();
+    }
+
+    pub(crate) fn focus_next(
+        &mut self,
+        _: &FocusNext,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.move_focus(false, window, cx);
+    }
+
+    pub(crate) fn focus_previous(
+        &mut self,
+        _: &FocusPrevious,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.move_focus(true, window, cx);
+    }
+
+    /// Wraps a control in a focusable container that draws a ring while focused,
+    /// activates it with Enter/Space and steps composite controls with the arrow keys.
+    pub(crate) fn focus_ring(
+        &self,
+        target: FocusTarget,
+        child: impl IntoElement,
+        window: &Window,
+        cx: &mut Context<Self>,
+    ) -> Div {
+        let handle = self.focus_rings.handle(target);
+        let focused = handle.is_focused(window);
+        div()
+            .relative()
+            .track_focus(handle)
+            .rounded(cx.theme().radius)
+            .border_2()
+            .border_color(if focused {
+                cx.theme().ring
+            } else {
+                transparent_black()
+            })
+            .on_key_down(cx.listener(move |this, ev: &KeyDownEvent, window, cx| {
+                if ev.keystroke.modifiers.modified() {
+                    return;
+                }
+                match ev.keystroke.key.as_str() {
+                    "enter" | "space" => this.activate(target, window, cx),
+                    "left" | "up" => this.step(target, -1, window, cx),
+                    "right" | "down" => this.step(target, 1, window, cx),
+                    _ => return,
+                }
+                cx.stop_propagation();
+                cx.notify();
+            }))
+            .child(child)
+            .map(|this| self.inspector.attach(format!("{:?}", target), this))
+    }
+
+    /// Performs the control's click action.
+    fn activate(&mut self, target: FocusTarget, window: &mut Window, cx: &mut Context<Self>) {
+        match target {
+            FocusTarget::Notify => crate::sections::buttons::notify(window, cx),
+            FocusTarget::OpenDialog => crate::sections::buttons::open_dialog(window, cx),
+            FocusTarget::OpenSheet => crate::sections::buttons::open_sheet(window, cx),
+            FocusTarget::ConfirmReset => self.confirm_reset(window, cx),
+            FocusTarget::PromptName => self.ask_name(window, cx),
+            FocusTarget::Agree => self.apply(Change::Checkbox(!self.checkbox_checked), window, cx),
+            FocusTarget::Enable => self.apply(Change::Switch(!self.switch_on), window, cx),
+            FocusTarget::FormSubscribe => self.form_subscribe = !self.form_subscribe,
+            FocusTarget::AccordionMultiple => {
+                self.set_accordion_multiple(!self.accordion_multiple, cx)
+            }
+            FocusTarget::ExpandAll if self.accordion_multiple => self.expand_accordion(cx),
+            FocusTarget::CollapseAll => self.collapse_accordion(cx),
+            FocusTarget::Collapsible => self.collapsible_open = !self.collapsible_open,
+            FocusTarget::CommandPalette => self.open_command_palette(window, cx),
+            FocusTarget::DocsLink => cx.open_url("https://gpui.rs"),
+            FocusTarget::SheetLeft
+            | FocusTarget::SheetRight
+            | FocusTarget::SheetTop
+            | FocusTarget::SheetBottom => self.activate_sheet_target(target, window, cx),
+            FocusTarget::JobDownload | FocusTarget::JobHash | FocusTarget::JobUpload => {
+                self.activate_job_target(target, cx)
+            }
+            FocusTarget::NavMenu => self.open_nav_drawer(window, cx),
+            FocusTarget::RouteBack => {
+                self.pop_route(cx);
+            }
+            FocusTarget::OpenFormsPage => self.navigate_to_path("/section/forms", cx),
+            FocusTarget::OpenTodo => self.navigate_to_path("/demo/todo", cx),
+            FocusTarget::DocsBack => self.docs.back(cx),
+            FocusTarget::DocsHome => self.docs.home(cx),
+            FocusTarget::Undo => self.undo(&Undo, window, cx),
+            FocusTarget::Redo => self.redo(&Redo, window, cx),
+            FocusTarget::Notifications => crate::notifications::open_center(window, cx),
+            FocusTarget::Shortcuts => crate::keymap::open_shortcuts_sheet(window, cx),
+            FocusTarget::WizardBack => self.wizard_back(window, cx),
+            FocusTarget::WizardNext => self.wizard_next(window, cx),
+            FocusTarget::ReloadData => self.reload_all_data(cx),
+            FocusTarget::ToggleBottomPanel => self.toggle_bottom_panel(cx),
+            FocusTarget::ResetPanels => self.panels.reset(),
+            FocusTarget::ToggleSide => self.toggle_sidebar_side(&ToggleSidebarSide, window, cx),
+            FocusTarget::ToggleCollapse => self.toggle_sidebar(&ToggleSidebar, window, cx),
+            target => self.step(target, 1, window, cx),
+        }
+    }
+
+    /// Moves the selection inside a composite control by `delta`.
+    fn step(
+        &mut self,
+        target: FocusTarget,
+        delta: isize,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        fn offset(value: usize, delta: isize, min: usize, max: usize) -> usize {
+            (value as isize + delta).clamp(min as isize, max as isize) as usize
+        }
+
+        match target {
+            FocusTarget::ButtonGroup => {
+                self.button_group_index = offset(self.button_group_index, delta, 0, 2)
+            }
+            FocusTarget::Radio => self.apply(Change::Radio(delta < 0), window, cx),
+            FocusTarget::Slider => {
+                let value = (self.slider_value + delta as f32).clamp(0., 100.);
+                self.apply(Change::Slider(value), window, cx);
+            }
+            FocusTarget::Rating => {
+                let value = offset(self.rating_value, delta, 0, 5);
+                self.apply(Change::Rating(value), window, cx);
+            }
+            FocusTarget::HalfRating => {
+                self.half_rating = (self.half_rating + delta as f32 * 0.5).clamp(0., 5.);
+            }
+            FocusTarget::Stepper => {
+                let step = offset(self.stepper_step, delta, 0, 2);
+                if self.can_visit_step(step) {
+                    self.wizard.error = None;
+                    self.apply(Change::Stepper(step), window, cx);
+                }
+            }
+            FocusTarget::Tabs => {
+                let ix = offset(self.tab_index, delta, 0, self.tabs.len() - 1);
+                self.apply(Change::Tab(ix), window, cx);
+            }
+            FocusTarget::Pagination => {
+                let page = offset(self.pagination_page, delta, 1, self.table_page_count(cx));
+                self.apply(Change::Page(page), window, cx);
+            }
+            FocusTarget::MarkdownTabs => self.markdown_tab = offset(self.markdown_tab, delta, 0, 1),
+            FocusTarget::Sidebar => self.step_nav(delta, cx),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{size, Entity, TestAppContext, VisualTestContext};
+
+    use super::*;
+    use crate::test_support::open_gallery;
+
+    const HEADER: [FocusTarget; 6] = [
+        FocusTarget::Search,
+        FocusTarget::Language,
+        FocusTarget::Undo,
+        FocusTarget::Redo,
+        FocusTarget::Notifications,
+        FocusTarget::Shortcuts,
+    ];
+
+    /// Tabs from nothing focused until focus wraps back to the first target,
+    /// returning every target visited in order.
+    fn walk(reverse: bool, is_compact: bool, route: Route) -> Vec<FocusTarget> {
+        let first = next_target(None, reverse, is_compact, route);
+        let mut visited = vec![first];
+        loop {
+            let next = next_target(visited.last().copied(), reverse, is_compact, route);
+            if next == first {
+                return visited;
+            }
+            assert!(!visited.contains(&next), "{next:?} reached twice");
+            visited.push(next);
+        }
+    }
+
+    fn routes() -> Vec<Route> {
+        let mut routes = vec![Route::Home, Route::Demo(Demo::Todo), Route::Settings];
+        routes.extend(
+            Section::ALL
+                .into_iter()
+                .filter(|section| *section != Section::Header)
+                .map(Route::Section),
+        );
+        routes
+    }
+
+    #[test]
+    fn wide_home_page_reaches_every_control_once() {
+        let skipped = [
+            FocusTarget::NavMenu,
+            FocusTarget::RouteBack,
+            FocusTarget::TodoInput,
+            FocusTarget::MarkdownTabs,
+        ];
+        let expected = FocusTarget::ORDER
+            .iter()
+            .copied()
+            .filter(|target| !skipped.contains(target))
+            .collect::<Vec<_>>();
+
+        assert_eq!(walk(false, false, Route::Home), expected);
+    }
+
+    #[test]
+    fn compact_home_page_swaps_the_sidebar_for_the_menu_and_adds_markdown_tabs() {
+        let order = walk(false, true, Route::Home);
+
+        assert_eq!(order[0], FocusTarget::NavMenu);
+        assert!(order.contains(&FocusTarget::MarkdownTabs));
+        assert!(!order.contains(&FocusTarget::Sidebar));
+        assert!(!order.contains(&FocusTarget::RouteBack));
+        assert_eq!(order.len(), FocusTarget::ORDER.len() - 3);
+    }
+
+    #[test]
+    fn section_pages_reach_the_header_back_button_and_their_own_controls() {
+        let mut expected = vec![FocusTarget::Sidebar];
+        expected.extend(HEADER);
+        expected.extend([
+            FocusTarget::RouteBack,
+            FocusTarget::Slider,
+            FocusTarget::SliderInput,
+            FocusTarget::Rating,
+            FocusTarget::HalfRating,
+            FocusTarget::Stepper,
+            FocusTarget::WizardBack,
+            FocusTarget::WizardNext,
+        ]);
+        assert_eq!(
+            walk(false, false, Route::Section(Section::Controls)),
+            expected
+        );
+
+        let mut expected = vec![FocusTarget::Sidebar];
+        expected.extend(HEADER);
+        expected.extend([FocusTarget::RouteBack, FocusTarget::TodoInput]);
+        assert_eq!(walk(false, false, Route::Demo(Demo::Todo)), expected);
+
+        let mut expected = vec![FocusTarget::NavMenu];
+        expected.extend(HEADER);
+        expected.extend([
+            FocusTarget::RouteBack,
+            FocusTarget::MarkdownTabs,
+            FocusTarget::MarkdownSource,
+        ]);
+        assert_eq!(
+            walk(false, true, Route::Section(Section::Markdown)),
+            expected
+        );
+    }
+
+    #[test]
+    fn shift_tab_walks_the_same_order_backwards_and_both_wrap() {
+        for is_compact in [false, true] {
+            for route in routes() {
+                let forward = walk(false, is_compact, route);
+                let mut backward = walk(true, is_compact, route);
+                backward.reverse();
+                assert_eq!(forward, backward, "{route:?}, compact: {is_compact}");
+
+                let (first, last) = (forward[0], forward[forward.len() - 1]);
+                assert_eq!(next_target(Some(last), false, is_compact, route), first);
+                assert_eq!(next_target(Some(first), true, is_compact, route), last);
+                assert!(forward
+                    .iter()
+                    .all(|target| target.is_rendered(is_compact, route)));
+            }
+        }
+    }
+
+    #[test]
+    fn focus_on_a_hidden_target_restarts_from_the_ends() {
+        let route = Route::Section(Section::Forms);
+
+        assert_eq!(
+            next_target(Some(FocusTarget::Tree), false, false, route),
+            FocusTarget::Sidebar
+        );
+        assert_eq!(
+            next_target(Some(FocusTarget::Tree), true, false, route),
+            FocusTarget::FormNotes
+        );
+    }
+
+    fn open_on(
+        route: Route,
+        is_compact: bool,
+        cx: &mut TestAppContext,
+    ) -> (Entity<ComponentGallery>, &mut VisualTestContext) {
+        let (gallery, cx) = open_gallery(cx);
+        let width = if is_compact { 400. } else { 1200. };
+        cx.simulate_resize(size(px(width), px(900.)));
+        gallery.update_in(cx, |this, _, cx| this.navigate(route, cx));
+        cx.run_until_parked();
+        (gallery, cx)
+    }
+
+    /// Presses `keys` once per target in `expected`, checking focus lands on
+    /// each in turn. Every press is dispatched from the element the previous
+    /// one focused, so a handle that was focused but not rendered would not
+    /// reach the gallery's bindings and the walk would stall.
+    fn press_through(
+        gallery: &Entity<ComponentGallery>,
+        cx: &mut VisualTestContext,
+        keys: &str,
+        expected: &[FocusTarget],
+    ) {
+        for target in expected {
+            cx.simulate_keystrokes(keys);
+            gallery.update_in(cx, |this, window, cx| {
+                assert!(
+                    this.target_handle(*target, cx).is_focused(window),
+                    "{keys} should focus {target:?}"
+                );
+                assert_eq!(this.focused_target(window, cx), Some(*target));
+            });
+        }
+    }
+
+    #[gpui::test]
+    fn tab_walks_a_wide_section_page_and_wraps(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_on(Route::Section(Section::Controls), false, cx);
+        let mut expected = vec![FocusTarget::Sidebar];
+        expected.extend(HEADER);
+        expected.extend([
+            FocusTarget::RouteBack,
+            FocusTarget::Slider,
+            FocusTarget::SliderInput,
+            FocusTarget::Rating,
+            FocusTarget::HalfRating,
+            FocusTarget::Stepper,
+            FocusTarget::WizardBack,
+            FocusTarget::WizardNext,
+            FocusTarget::Sidebar,
+        ]);
+        // The menu button only exists in compact layouts, so it is skipped.
+        assert!(!expected.contains(&FocusTarget::NavMenu));
+        press_through(&gallery, cx, "tab", &expected);
+
+        press_through(
+            &gallery,
+            cx,
+            "shift-tab",
+            &[
+                FocusTarget::WizardNext,
+                FocusTarget::WizardBack,
+                FocusTarget::Stepper,
+            ],
+        );
+    }
+
+    #[gpui::test]
+    fn tab_walks_a_compact_section_page_and_wraps(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_on(Route::Section(Section::Controls), true, cx);
+        let mut expected = vec![FocusTarget::NavMenu];
+        expected.extend(HEADER);
+        expected.extend([
+            FocusTarget::RouteBack,
+            FocusTarget::Slider,
+            FocusTarget::SliderInput,
+            FocusTarget::Rating,
+            FocusTarget::HalfRating,
+            FocusTarget::Stepper,
+            FocusTarget::WizardBack,
+            FocusTarget::WizardNext,
+            FocusTarget::NavMenu,
+        ]);
+        // The docked sidebar gives way to the menu's drawer.
+        assert!(!expected.contains(&FocusTarget::Sidebar));
+        press_through(&gallery, cx, "tab", &expected);
+
+        press_through(
+            &gallery,
+            cx,
+            "shift-tab",
+            &[
+                FocusTarget::WizardNext,
+                FocusTarget::WizardBack,
+                FocusTarget::Stepper,
+            ],
+        );
+    }
+
+    #[gpui::test]
+    fn shift_tab_from_nothing_focused_starts_at_the_last_control(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_on(Route::Demo(Demo::Todo), true, cx);
+        press_through(
+            &gallery,
+            cx,
+            "shift-tab",
+            &[
+                FocusTarget::TodoInput,
+                FocusTarget::RouteBack,
+                FocusTarget::Shortcuts,
+            ],
+        );
+    }
+}
@@ -0,0 +1,250 @@
+use std::time::{Duration, Instant};
+
+use gpui::{actions, Context, Window};
+
+use crate::ComponentGallery;
+
+actions!(gallery, [Undo, Redo]);
+
+/// Consecutive changes to a mergeable control within this window become one step.
+const MERGE_WINDOW: Duration = Duration::from_millis(500);
+
+/// How many steps are kept before the oldest is dropped.
+const HISTORY_LIMIT: usize = 100;
+
+/// A new value for one of the gallery's undoable controls.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Change {
+    Checkbox(bool),
+    Switch(bool),
+    Radio(bool),
+    Rating(usize),
+    Stepper(usize),
+    Tab(usize),
+    Page(usize),
+    Slider(f32),
+    SidebarCollapsed(bool),
+    SidebarRight(bool),
+}
+
+impl Change {
+    fn same_control(&self, other: &Change) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Whether rapid changes, such as a slider drag, collapse into one step.
+    fn merges(&self) -> bool {
+        matches!(self, Change::Slider(_))
+    }
+}
+
+struct Entry {
+    before: Change,
+    after: Change,
+    at: Instant,
+}
+
+#[derive(Default)]
+pub struct History {
+    undo: Vec<Entry>,
+    redo: Vec<Entry>,
+}
+
+impl History {
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Records that a control went from `before` to `after`, discarding the redo stack.
+    pub fn record(&mut self, before: Change, after: Change) {
+        if before == after {
+            return;
+        }
+        self.redo.clear();
+
+        let now = Instant::now();
+        if let Some(last) = self.undo.last_mut() {
+            if after.merges()
+                && last.after.same_control(&after)
+                && now.duration_since(last.at) < MERGE_WINDOW
+            {
+                last.after = after;
+                last.at = now;
+                if last.before == last.after {
+                    self.undo.pop();
+                }
+                return;
+            }
+        }
+
+        self.undo.push(Entry {
+            before,
+            after,
+            at: now,
+        });
+        if self.undo.len() > HISTORY_LIMIT {
+            self.undo.remove(0);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Rewrites recorded tab indices after tabs were closed or reordered.
+    /// `remap` gives a tab's new index, or `None` if it was closed; steps to
+    /// or from a closed tab are dropped.
+    pub fn remap_tabs(&mut self, remap: impl Fn(usize) -> Option<usize>) {
+        let remap_change = |change: &mut Change| match change {
+            Change::Tab(ix) => remap(*ix).map(|new| *ix = new).is_some(),
+            _ => true,
+        };
+        for stack in [&mut self.undo, &mut self.redo] {
+            stack.retain_mut(|entry| {
+                remap_change(&mut entry.before) && remap_change(&mut entry.after)
+            });
+        }
+    }
+}
+
+impl ComponentGallery {
+    /// The current value of the control that `change` targets.
+    fn current(&self, change: Change) -> Change {
+        match change {
+            Change::Checkbox(_) => Change::Checkbox(self.checkbox_checked),
+            Change::Switch(_) => Change::Switch(self.switch_on),
+            Change::Radio(_) => Change::Radio(self.radio_checked),
+            Change::Rating(_) => Change::Rating(self.rating_value),
+            Change::Stepper(_) => Change::Stepper(self.stepper_step),
+            Change::Tab(_) => Change::Tab(self.tab_index),
+            Change::Page(_) => Change::Page(self.pagination_page),
+            Change::Slider(_) => Change::Slider(self.slider_value),
+            Change::SidebarCollapsed(_) => Change::SidebarCollapsed(self.sidebar_collapsed),
+            Change::SidebarRight(_) => Change::SidebarRight(self.sidebar_side_right),
+        }
+    }
+
+    /// Sets a control's value, returning false if it may no longer take it.
+    fn set(&mut self, change: Change, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        match change {
+            Change::Checkbox(value) => self.checkbox_checked = value,
+            Change::Switch(value) => self.switch_on = value,
+            Change::Radio(value) => self.radio_checked = value,
+            Change::Rating(value) => self.rating_value = value,
+            // The wizard may have been restarted since, so re-check the step.
+            Change::Stepper(value) if !self.can_visit_step(value) => return false,
+            Change::Stepper(value) => self.stepper_step = value,
+            Change::Tab(value) => self.select_tab(value, cx),
+            Change::Page(value) => self.set_table_page(value, cx),
+            Change::Slider(value) => self.set_slider_value(value, window, cx),
+            Change::SidebarCollapsed(value) => self.sidebar_collapsed = value,
+            Change::SidebarRight(value) => self.sidebar_side_right = value,
+        }
+        cx.notify();
+        true
+    }
+
+    /// Applies a user change to a control and records it for undo.
+    pub(crate) fn apply(&mut self, change: Change, window: &mut Window, cx: &mut Context<Self>) {
+        let before = self.current(change);
+        if self.set(change, window, cx) {
+            self.history.record(before, change);
+        }
+    }
+
+    /// Undoes the latest step, dropping any that can no longer be applied.
+    pub(crate) fn undo(&mut self, _: &Undo, window: &mut Window, cx: &mut Context<Self>) {
+        while let Some(entry) = self.history.undo.pop() {
+            if self.set(entry.before, window, cx) {
+                self.history.redo.push(entry);
+                return;
+            }
+        }
+    }
+
+    /// Redoes the latest undone step, dropping any that can no longer be applied.
+    pub(crate) fn redo(&mut self, _: &Redo, window: &mut Window, cx: &mut Context<Self>) {
+        while let Some(entry) = self.history.redo.pop() {
+            if self.set(entry.after, window, cx) {
+                self.history.undo.push(entry);
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn steps(entries: &[Entry]) -> Vec<(Change, Change)> {
+        entries
+            .iter()
+            .map(|entry| (entry.before, entry.after))
+            .collect()
+    }
+
+    #[test]
+    fn slider_drags_merge_into_one_step() {
+        let mut history = History::default();
+        history.record(Change::Slider(10.), Change::Slider(20.));
+        history.record(Change::Slider(20.), Change::Slider(30.));
+        history.record(Change::Checkbox(true), Change::Checkbox(false));
+
+        assert_eq!(
+            steps(&history.undo),
+            vec![
+                (Change::Slider(10.), Change::Slider(30.)),
+                (Change::Checkbox(true), Change::Checkbox(false)),
+            ]
+        );
+    }
+
+    #[test]
+    fn closing_a_tab_shifts_later_tabs_and_drops_steps_involving_it() {
+        let mut history = History::default();
+        history.record(Change::Tab(0), Change::Tab(2));
+        history.record(Change::Tab(1), Change::Tab(0));
+        history.record(Change::Switch(false), Change::Switch(true));
+        history.record(Change::Tab(2), Change::Tab(1));
+        history.record(Change::Tab(2), Change::Tab(3));
+        // The last two steps were undone.
+        history.redo.push(history.undo.pop().unwrap());
+        history.redo.push(history.undo.pop().unwrap());
+
+        // Tab 1 was closed.
+        history.remap_tabs(|ix| match ix {
+            1 => None,
+            ix if ix > 1 => Some(ix - 1),
+            ix => Some(ix),
+        });
+
+        assert_eq!(
+            steps(&history.undo),
+            vec![
+                (Change::Tab(0), Change::Tab(1)),
+                (Change::Switch(false), Change::Switch(true)),
+            ]
+        );
+        assert_eq!(steps(&history.redo), vec![(Change::Tab(1), Change::Tab(2))]);
+    }
+
+    #[test]
+    fn moving_a_tab_follows_it_on_both_stacks() {
+        let mut history = History::default();
+        history.record(Change::Tab(0), Change::Tab(2));
+        history.record(Change::Tab(2), Change::Tab(1));
+        history.redo.push(history.undo.pop().unwrap());
+
+        // The first of three tabs was dragged to the end.
+        history.remap_tabs(|ix| Some(if ix == 0 { 2 } else { ix - 1 }));
+
+        assert_eq!(steps(&history.undo), vec![(Change::Tab(2), Change::Tab(1))]);
+        assert_eq!(steps(&history.redo), vec![(Change::Tab(1), Change::Tab(0))]);
+    }
+}
@@ -0,0 +1,123 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use chrono::NaiveDate;
+use gpui::{App, Global, SharedString};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Locale {
+    En,
+    ZhCn,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::En, Locale::ZhCn];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::ZhCn => "zh-CN",
+        }
+    }
+
+    /// The language name written in that language, as shown in the picker.
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::ZhCn => "简体中文",
+        }
+    }
+
+    fn messages(self) -> &'static str {
+        match self {
+            Locale::En => include_str!("../assets/locales/en.json"),
+            Locale::ZhCn => include_str!("../assets/locales/zh-CN.json"),
+        }
+    }
+}
+
+fn load_messages(locale: Locale) -> HashMap<String, String> {
+    serde_json::from_str(locale.messages()).unwrap_or_else(|err| {
+        log::error!("invalid message file for {}: {}", locale.code(), err);
+        HashMap::new()
+    })
+}
+
+pub struct I18n {
+    locale: Locale,
+    fallback: HashMap<String, String>,
+    messages: HashMap<String, String>,
+    /// Keys already reported as missing, so each is only logged once.
+    reported: RefCell<HashSet<(Locale, String)>>,
+}
+
+impl Global for I18n {}
+
+impl I18n {
+    fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            fallback: load_messages(Locale::En),
+            messages: load_messages(locale),
+            reported: RefCell::new(HashSet::new()),
+        }
+    }
+
+    fn lookup(&self, key: &str) -> SharedString {
+        if let Some(message) = self.messages.get(key) {
+            return message.clone().into();
+        }
+
+        if self
+            .reported
+            .borrow_mut()
+            .insert((self.locale, key.to_string()))
+        {
+            log::warn!("missing {} message for \"{}\"", self.locale.code(), key);
+        }
+
+        match self.fallback.get(key) {
+            Some(message) => message.clone().into(),
+            None => key.to_string().into(),
+        }
+    }
+}
+
+pub fn init(cx: &mut App) {
+    cx.set_global(I18n::new(Locale::En));
+}
+
+pub fn locale(cx: &App) -> Locale {
+    cx.global::<I18n>().locale
+}
+
+/// Switches the active locale and re-renders every window.
+pub fn set_locale(locale: Locale, cx: &mut App) {
+    if self::locale(cx) == locale {
+        return;
+    }
+    let i18n = cx.global_mut::<I18n>();
+    i18n.messages = load_messages(locale);
+    i18n.locale = locale;
+    cx.refresh_windows();
+}
+
+/// Looks up `key` in the active locale, falling back to English.
+pub fn t(cx: &App, key: &str) -> SharedString {
+    cx.global::<I18n>().lookup(key)
+}
+
+/// Like [`t`], replacing `{name}` placeholders with the given arguments.
+pub fn t_args(cx: &App, key: &str, args: &[(&str, &dyn Display)]) -> SharedString {
+    let mut message = t(cx, key).to_string();
+    for (name, value) in args {
+        message = message.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    message.into()
+}
+
+/// Formats a date the way the active locale writes it.
+pub fn format_date(cx: &App, date: NaiveDate) -> SharedString {
+    date.format(&t(cx, "common.date_format")).to_string().into()
+}
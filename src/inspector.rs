@@ -0,0 +1,358 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gpui::{
+    canvas, div, prelude::*, px, AbsoluteLength, AnyElement, App, Bounds, Context, DefiniteLength,
+    EdgesRefinement, Fill, Hsla, Length, MouseButton, MouseDownEvent, Pixels, Rgba, SharedString,
+    StyleRefinement, Window,
+};
+use gpui_component::{
+    button::{Button, ButtonVariants},
+    h_flex, v_flex, ActiveTheme as _, Sizable, StyledExt as _,
+};
+
+use crate::i18n::t;
+use crate::sections::Section;
+use crate::ComponentGallery;
+
+/// What the inspector knows about one element: the style it was built with,
+/// read off the element itself, and its bounds once laid out.
+#[derive(Clone)]
+pub struct InspectEntry {
+    pub id: SharedString,
+    pub section: Section,
+    style: StyleRefinement,
+    bounds: Option<Bounds<Pixels>>,
+}
+
+/// Inspector mode: while enabled, cards and focusable controls report their
+/// bounds each frame, and taps select the innermost element under the pointer.
+pub struct Inspector {
+    enabled: bool,
+    section: Section,
+    entries: Rc<RefCell<Vec<InspectEntry>>>,
+    /// Last frame's entries, whose bounds are known by the time we render.
+    previous: Vec<InspectEntry>,
+    selected: Option<SharedString>,
+}
+
+impl Default for Inspector {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            section: Section::Header,
+            entries: Rc::default(),
+            previous: Vec::new(),
+            selected: None,
+        }
+    }
+}
+
+impl Inspector {
+    /// Called before rendering the page; keeps last frame's entries for drawing
+    /// the highlight and starts collecting this frame's.
+    pub fn begin_frame(&mut self) {
+        self.previous = std::mem::take(&mut *self.entries.borrow_mut());
+    }
+
+    /// Sets the section that elements registered from now on belong to.
+    pub fn set_section(&mut self, section: Section) {
+        self.section = section;
+    }
+
+    /// Registers `element` under `id` and adds an invisible probe that records
+    /// its bounds once laid out. Call it after the element is styled; it must
+    /// be `relative()` so the probe covers it.
+    pub fn attach<E: Styled + ParentElement>(
+        &self,
+        id: impl Into<SharedString>,
+        mut element: E,
+    ) -> E {
+        if !self.enabled {
+            return element;
+        }
+        let entries = self.entries.clone();
+        let ix = {
+            let mut entries = entries.borrow_mut();
+            entries.push(InspectEntry {
+                id: id.into(),
+                section: self.section,
+                style: element.style().clone(),
+                bounds: None,
+            });
+            entries.len() - 1
+        };
+        element.child(
+            canvas(
+                move |bounds, _, _| {
+                    if let Some(entry) = entries.borrow_mut().get_mut(ix) {
+                        entry.bounds = Some(bounds);
+                    }
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .inset_0(),
+        )
+    }
+
+    fn selected_entry(&self) -> Option<InspectEntry> {
+        let selected = self.selected.as_ref()?;
+        self.previous
+            .iter()
+            .find(|entry| &entry.id == selected)
+            .cloned()
+    }
+
+    /// The smallest registered element containing `position`.
+    fn hit_test(&self, position: gpui::Point<Pixels>) -> Option<SharedString> {
+        self.entries
+            .borrow()
+            .iter()
+            .filter_map(|entry| {
+                let bounds = entry.bounds?;
+                bounds.contains(&position).then(|| {
+                    let area = f32::from(bounds.size.width) * f32::from(bounds.size.height);
+                    (area, entry.id.clone())
+                })
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, id)| id)
+    }
+}
+
+fn hex(color: Hsla) -> String {
+    let rgba = Rgba::from(color);
+    let channel = |value: f32| (value.clamp(0., 1.) * 255.).round() as u8;
+    if rgba.a <= 0. {
+        return "transparent".into();
+    }
+    format!(
+        "#{:02x}{:02x}{:02x}{:02x}",
+        channel(rgba.r),
+        channel(rgba.g),
+        channel(rgba.b),
+        channel(rgba.a)
+    )
+}
+
+/// Lays out the four sides as CSS does, collapsing them when they are equal.
+/// Sides the element doesn't set are zero.
+fn edges<T>(edges: &EdgesRefinement<T>, length: impl Fn(&T) -> String) -> String {
+    let sides = [&edges.top, &edges.right, &edges.bottom, &edges.left]
+        .map(|side| side.as_ref().map_or_else(|| "0".to_string(), &length));
+    if sides.iter().all(|side| *side == sides[0]) {
+        sides[0].clone()
+    } else {
+        sides.join(" ")
+    }
+}
+
+fn absolute(length: &AbsoluteLength, rem_size: Pixels) -> String {
+    format!("{:.0}", f32::from(length.to_pixels(rem_size)))
+}
+
+fn definite(length: &DefiniteLength, rem_size: Pixels) -> String {
+    match length {
+        DefiniteLength::Absolute(length) => absolute(length, rem_size),
+        DefiniteLength::Fraction(fraction) => format!("{:.0}%", fraction * 100.),
+    }
+}
+
+/// Theme colors, so the panel can say which token a color came from.
+fn theme_colors(cx: &App) -> [(&'static str, Hsla); 10] {
+    let theme = cx.theme();
+    [
+        ("background", theme.background),
+        ("foreground", theme.foreground),
+        ("muted", theme.muted),
+        ("muted_foreground", theme.muted_foreground),
+        ("secondary", theme.secondary),
+        ("accent", theme.accent),
+        ("primary", theme.primary),
+        ("popover", theme.popover),
+        ("border", theme.border),
+        ("ring", theme.ring),
+    ]
+}
+
+fn color(color: Option<Hsla>, cx: &App) -> SharedString {
+    let Some(color) = color else {
+        return t(cx, "inspector.unset");
+    };
+    match theme_colors(cx).iter().find(|(_, token)| *token == color) {
+        Some((name, _)) => format!("{} {}", name, hex(color)).into(),
+        None => hex(color).into(),
+    }
+}
+
+/// Backgrounds can be gradients, so only solid theme colors are named.
+fn fill(fill: Option<&Fill>, cx: &App) -> SharedString {
+    let Some(fill) = fill else {
+        return t(cx, "inspector.unset");
+    };
+    match theme_colors(cx)
+        .iter()
+        .find(|(_, token)| Fill::from(*token) == *fill)
+    {
+        Some((name, token)) => format!("{} {}", name, hex(*token)).into(),
+        None => t(cx, "inspector.custom"),
+    }
+}
+
+fn property(label: SharedString, value: impl Into<SharedString>) -> impl IntoElement {
+    h_flex()
+        .gap_3()
+        .justify_between()
+        .child(label)
+        .child(div().font_family("monospace").child(value.into()))
+}
+
+impl ComponentGallery {
+    /// Turns on inspector mode, optionally selecting an element right away.
+    pub(crate) fn inspect(&mut self, id: Option<SharedString>, cx: &mut Context<Self>) {
+        self.inspector.enabled = true;
+        self.inspector.selected = id;
+        cx.notify();
+    }
+
+    pub(crate) fn exit_inspector(&mut self, cx: &mut Context<Self>) {
+        self.inspector.enabled = false;
+        self.inspector.selected = None;
+        cx.notify();
+    }
+
+    /// The highlight and details panel drawn over the page in inspector mode.
+    pub(crate) fn render_inspector(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Option<AnyElement> {
+        if !self.inspector.enabled {
+            return None;
+        }
+
+        let rem_size = window.rem_size();
+        let selected = self.inspector.selected_entry();
+        let highlight = selected
+            .as_ref()
+            .and_then(|entry| entry.bounds)
+            .map(|bounds| {
+                div()
+                    .absolute()
+                    .left(bounds.origin.x)
+                    .top(bounds.origin.y)
+                    .w(bounds.size.width)
+                    .h(bounds.size.height)
+                    .border_2()
+                    .border_color(cx.theme().primary)
+                    .bg(cx.theme().primary.opacity(0.1))
+            });
+
+        let details = match selected {
+            Some(entry) => {
+                let bounds = entry.bounds.unwrap_or_default();
+                let style = &entry.style;
+                v_flex()
+                    .gap_1()
+                    .text_sm()
+                    .child(property(t(cx, "inspector.id"), entry.id.clone()))
+                    .child(property(
+                        t(cx, "inspector.section"),
+                        t(cx, entry.section.title_key()),
+                    ))
+                    .child(property(
+                        t(cx, "inspector.bounds"),
+                        format!(
+                            "{:.0}, {:.0}  {:.0} × {:.0}",
+                            f32::from(bounds.origin.x),
+                            f32::from(bounds.origin.y),
+                            f32::from(bounds.size.width),
+                            f32::from(bounds.size.height)
+                        ),
+                    ))
+                    .child(property(
+                        t(cx, "inspector.padding"),
+                        edges(&style.padding, |length| definite(length, rem_size)),
+                    ))
+                    .child(property(
+                        t(cx, "inspector.margin"),
+                        edges(&style.margin, |length| match length {
+                            Length::Definite(length) => definite(length, rem_size),
+                            Length::Auto => "auto".into(),
+                        }),
+                    ))
+                    .child(property(
+                        t(cx, "inspector.border"),
+                        edges(&style.border_widths, |length| absolute(length, rem_size)),
+                    ))
+                    .child(property(
+                        t(cx, "inspector.border_color"),
+                        color(style.border_color, cx),
+                    ))
+                    .child(property(
+                        t(cx, "inspector.background"),
+                        fill(style.background.as_ref(), cx),
+                    ))
+                    .child(property(
+                        t(cx, "inspector.text"),
+                        color(style.text.as_ref().and_then(|text| text.color), cx),
+                    ))
+                    .into_any_element()
+            }
+            None => div()
+                .text_sm()
+                .text_color(cx.theme().muted_foreground)
+                .child(t(cx, "inspector.hint"))
+                .into_any_element(),
+        };
+
+        let panel = v_flex()
+            .id("inspector-panel")
+            .occlude()
+            .absolute()
+            .right_3()
+            .bottom_3()
+            .w(px(300.))
+            .gap_2()
+            .p_3()
+            .rounded_lg()
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().popover)
+            .shadow_lg()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .items_center()
+                    .child(div().font_semibold().child(t(cx, "inspector.title")))
+                    .child(
+                        Button::new("exit-inspector")
+                            .label(t(cx, "inspector.exit"))
+                            .ghost()
+                            .small()
+                            .on_click(cx.listener(|this, _, _, cx| this.exit_inspector(cx))),
+                    ),
+            )
+            .child(details);
+
+        Some(
+            div()
+                .id("inspector")
+                .absolute()
+                .inset_0()
+                .occlude()
+                .cursor_crosshair()
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(|this, ev: &MouseDownEvent, _, cx| {
+                        this.inspector.selected = this.inspector.hit_test(ev.position);
+                        cx.notify();
+                    }),
+                )
+                .children(highlight)
+                .child(panel)
+                .into_any_element(),
+        )
+    }
+}
@@ -0,0 +1,333 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::channel::mpsc;
+use futures::StreamExt as _;
+use gpui::{Context, SharedString, Task};
+
+use crate::ComponentGallery;
+
+/// How often a running job reports progress, and how often a paused one
+/// checks whether it may continue.
+const TICK: Duration = Duration::from_millis(80);
+
+/// The simulated jobs the Feedback section can start.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JobKind {
+    /// A fake download in fixed-size chunks.
+    Download,
+    /// Hashes a generated file chunk by chunk and reports the digest.
+    Hash,
+    /// An upload that always fails part of the way through.
+    Upload,
+}
+
+impl JobKind {
+    pub fn label_key(self) -> &'static str {
+        match self {
+            JobKind::Download => "jobs.download",
+            JobKind::Hash => "jobs.hash",
+            JobKind::Upload => "jobs.upload",
+        }
+    }
+
+    fn chunks(self) -> usize {
+        match self {
+            JobKind::Download => 60,
+            JobKind::Hash => 40,
+            JobKind::Upload => 50,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum JobStatus {
+    Running,
+    Paused,
+    Cancelled,
+    /// Failed, with the message key of the reason.
+    Failed(SharedString),
+    /// Finished, with a short result such as the digest.
+    Done(SharedString),
+}
+
+impl JobStatus {
+    pub fn is_active(&self) -> bool {
+        matches!(self, JobStatus::Running | JobStatus::Paused)
+    }
+}
+
+enum JobUpdate {
+    Progress(f32),
+    Finished(Result<SharedString, SharedString>),
+}
+
+/// Flags the UI flips and the worker polls between chunks.
+#[derive(Default)]
+struct JobControl {
+    paused: AtomicBool,
+    cancelled: AtomicBool,
+}
+
+pub struct Job {
+    pub id: usize,
+    pub kind: JobKind,
+    /// Percent complete, 0–100.
+    pub progress: f32,
+    pub status: JobStatus,
+    control: Arc<JobControl>,
+    _work: Task<()>,
+    _updates: Task<()>,
+}
+
+/// Background jobs started from the gallery, oldest first.
+#[derive(Default)]
+pub struct Jobs {
+    entries: Vec<Job>,
+    next_id: usize,
+}
+
+impl Jobs {
+    pub fn iter(&self) -> impl Iterator<Item = &Job> {
+        self.entries.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn get_mut(&mut self, id: usize) -> Option<&mut Job> {
+        self.entries.iter_mut().find(|job| job.id == id)
+    }
+}
+
+/// FNV-1a, enough to give the hash job real work per chunk.
+fn fnv1a(state: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(state, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+impl ComponentGallery {
+    /// Starts `kind` on the background executor. The worker sends progress over
+    /// a channel that a foreground task applies to the job's row.
+    pub(crate) fn start_job(&mut self, kind: JobKind, cx: &mut Context<Self>) {
+        let id = self.jobs.next_id;
+        self.jobs.next_id += 1;
+
+        let control = Arc::new(JobControl::default());
+        let (tx, mut rx) = mpsc::unbounded();
+        let executor = cx.background_executor().clone();
+
+        let work = cx.background_spawn({
+            let control = control.clone();
+            async move {
+                let chunks = kind.chunks();
+                let mut hash = 0xcbf29ce484222325;
+                let mut done = 0;
+                while done < chunks {
+                    executor.timer(TICK).await;
+                    if control.cancelled.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    if control.paused.load(Ordering::Relaxed) {
+                        continue;
+                    }
+                    if kind == JobKind::Upload && done * 5 >= chunks * 3 {
+                        let error = SharedString::from("jobs.connection_lost");
+                        tx.unbounded_send(JobUpdate::Finished(Err(error))).ok();
+                        return;
+                    }
+                    if kind == JobKind::Hash {
+                        let chunk = vec![done as u8; 64 * 1024];
+                        hash = fnv1a(hash, &chunk);
+                    }
+                    done += 1;
+                    let progress = done as f32 / chunks as f32 * 100.;
+                    tx.unbounded_send(JobUpdate::Progress(progress)).ok();
+                }
+                let result = match kind {
+                    JobKind::Hash => format!("{:016x}", hash).into(),
+                    _ => SharedString::default(),
+                };
+                tx.unbounded_send(JobUpdate::Finished(Ok(result))).ok();
+            }
+        });
+
+        let updates = cx.spawn(async move |this, cx| {
+            while let Some(update) = rx.next().await {
+                let applied = this.update(cx, |this, cx| this.apply_job_update(id, update, cx));
+                if applied.is_err() {
+                    break;
+                }
+            }
+        });
+
+        self.jobs.entries.push(Job {
+            id,
+            kind,
+            progress: 0.,
+            status: JobStatus::Running,
+            control,
+            _work: work,
+            _updates: updates,
+        });
+        cx.notify();
+    }
+
+    fn apply_job_update(&mut self, id: usize, update: JobUpdate, cx: &mut Context<Self>) {
+        let Some(job) = self.jobs.get_mut(id) else {
+            return;
+        };
+        if !job.status.is_active() {
+            return;
+        }
+        match update {
+            JobUpdate::Progress(progress) => job.progress = progress,
+            JobUpdate::Finished(Ok(result)) => job.status = JobStatus::Done(result),
+            JobUpdate::Finished(Err(error)) => job.status = JobStatus::Failed(error),
+        }
+        cx.notify();
+    }
+
+    pub(crate) fn toggle_job_paused(&mut self, id: usize, cx: &mut Context<Self>) {
+        let Some(job) = self.jobs.get_mut(id) else {
+            return;
+        };
+        job.status = match job.status {
+            JobStatus::Running => JobStatus::Paused,
+            JobStatus::Paused => JobStatus::Running,
+            _ => return,
+        };
+        job.control
+            .paused
+            .store(job.status == JobStatus::Paused, Ordering::Relaxed);
+        cx.notify();
+    }
+
+    pub(crate) fn cancel_job(&mut self, id: usize, cx: &mut Context<Self>) {
+        let Some(job) = self.jobs.get_mut(id) else {
+            return;
+        };
+        if job.status.is_active() {
+            job.control.cancelled.store(true, Ordering::Relaxed);
+            job.status = JobStatus::Cancelled;
+            cx.notify();
+        }
+    }
+
+    /// Drops finished, failed and cancelled jobs from the list.
+    pub(crate) fn clear_finished_jobs(&mut self, cx: &mut Context<Self>) {
+        self.jobs.entries.retain(|job| job.status.is_active());
+        cx.notify();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{Entity, TestAppContext, VisualTestContext};
+
+    use super::*;
+    use crate::test_support::open_gallery;
+
+    fn start(
+        gallery: &Entity<ComponentGallery>,
+        kind: JobKind,
+        cx: &mut VisualTestContext,
+    ) -> usize {
+        gallery.update(cx, |this, cx| {
+            this.start_job(kind, cx);
+            this.jobs.next_id - 1
+        })
+    }
+
+    /// Lets `ticks` worker ticks pass and applies the updates they sent.
+    fn advance(ticks: u32, cx: &mut VisualTestContext) {
+        cx.executor().advance_clock(TICK * ticks);
+        cx.run_until_parked();
+    }
+
+    fn job(
+        gallery: &Entity<ComponentGallery>,
+        id: usize,
+        cx: &mut VisualTestContext,
+    ) -> (f32, JobStatus) {
+        gallery.read_with(cx, |this, _| {
+            let job = this.jobs.iter().find(|job| job.id == id).unwrap();
+            (job.progress, job.status.clone())
+        })
+    }
+
+    #[gpui::test]
+    fn a_paused_job_makes_no_progress_until_resumed(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        let id = start(&gallery, JobKind::Download, cx);
+        advance(3, cx);
+        let (progress, status) = job(&gallery, id, cx);
+        assert!(progress > 0.);
+        assert_eq!(status, JobStatus::Running);
+
+        gallery.update(cx, |this, cx| this.toggle_job_paused(id, cx));
+        advance(10, cx);
+        assert_eq!(job(&gallery, id, cx), (progress, JobStatus::Paused));
+
+        gallery.update(cx, |this, cx| this.toggle_job_paused(id, cx));
+        advance(1, cx);
+        let (resumed, status) = job(&gallery, id, cx);
+        assert!(resumed > progress);
+        assert_eq!(status, JobStatus::Running);
+    }
+
+    #[gpui::test]
+    fn a_cancelled_job_stops_and_ignores_late_updates(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        let id = start(&gallery, JobKind::Hash, cx);
+        advance(5, cx);
+        let (progress, _) = job(&gallery, id, cx);
+
+        gallery.update(cx, |this, cx| this.cancel_job(id, cx));
+        advance(100, cx);
+        assert_eq!(job(&gallery, id, cx), (progress, JobStatus::Cancelled));
+
+        // Updates the worker sent before it saw the flag arrive afterwards.
+        gallery.update(cx, |this, cx| {
+            this.apply_job_update(id, JobUpdate::Progress(90.), cx);
+            this.apply_job_update(id, JobUpdate::Finished(Ok("late".into())), cx);
+        });
+        assert_eq!(job(&gallery, id, cx), (progress, JobStatus::Cancelled));
+    }
+
+    #[gpui::test]
+    fn the_upload_fails_part_way_through(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        let id = start(&gallery, JobKind::Upload, cx);
+        advance(100, cx);
+        let (progress, status) = job(&gallery, id, cx);
+        assert_eq!(status, JobStatus::Failed("jobs.connection_lost".into()));
+        assert!((progress - 60.).abs() < 0.01);
+
+        // A failed job can't be paused or cancelled any more.
+        gallery.update(cx, |this, cx| {
+            this.toggle_job_paused(id, cx);
+            this.cancel_job(id, cx);
+        });
+        assert_eq!(job(&gallery, id, cx).1, status);
+    }
+
+    #[gpui::test]
+    fn a_hash_job_finishes_with_its_digest(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        let id = start(&gallery, JobKind::Hash, cx);
+        advance(JobKind::Hash.chunks() as u32 + 1, cx);
+        let (progress, status) = job(&gallery, id, cx);
+        assert_eq!(progress, 100.);
+        let JobStatus::Done(digest) = status else {
+            panic!("hash job ended as {:?}", status);
+        };
+        assert_eq!(digest.len(), 16);
+
+        gallery.update(cx, |this, cx| this.clear_finished_jobs(cx));
+        assert!(gallery.read_with(cx, |this, _| this.jobs.is_empty()));
+    }
+}
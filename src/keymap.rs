@@ -0,0 +1,402 @@
+use std::collections::{BTreeMap, HashMap};
+
+use gpui::{
+    actions, div, prelude::*, App, Context, Global, KeyBinding, Keystroke, SharedString, Window,
+};
+use gpui_component::{h_flex, kbd::Kbd, v_flex, ActiveTheme as _, WindowExt as _};
+
+use crate::command_palette::{
+    OpenDialog, OpenSheet, PushNotification, ResetState, ToggleCommandPalette, ToggleTheme,
+};
+use crate::focus::{FocusNext, FocusPrevious, CONTEXT};
+use crate::history::{Change, Redo, Undo};
+use crate::i18n::{t, t_args};
+use crate::router::GoBack;
+use crate::ComponentGallery;
+
+actions!(
+    gallery,
+    [
+        ToggleSidebar,
+        ToggleSidebarSide,
+        NextTab,
+        PreviousTab,
+        NextPage,
+        PreviousPage,
+        ShowKeyboardShortcuts
+    ]
+);
+
+/// Where users can drop a keymap to override the defaults. Maps keystrokes to action
+/// names; `null` unbinds a default keystroke.
+pub const USER_KEYMAP_PATH: &str = "/data/storage/el2/base/files/keymap.json";
+
+const DEFAULT_KEYMAP: &str = include_str!("../assets/keymap/default.json");
+
+/// Every bindable action with the message key used to describe it, in display order.
+pub const ACTIONS: &[(&str, &str)] = &[
+    ("ToggleCommandPalette", "keymap.toggle_command_palette"),
+    ("ShowKeyboardShortcuts", "keymap.show_keyboard_shortcuts"),
+    ("GoBack", "router.back"),
+    ("Undo", "history.undo"),
+    ("Redo", "history.redo"),
+    ("FocusNext", "keymap.focus_next"),
+    ("FocusPrevious", "keymap.focus_previous"),
+    ("ToggleTheme", "palette.toggle_theme"),
+    ("OpenDialog", "buttons.open_dialog"),
+    ("OpenSheet", "buttons.open_sheet"),
+    ("PushNotification", "palette.push_notification"),
+    ("ResetState", "palette.reset_state"),
+    ("ToggleSidebar", "keymap.toggle_sidebar"),
+    ("ToggleSidebarSide", "keymap.toggle_sidebar_side"),
+    ("NextTab", "keymap.next_tab"),
+    ("PreviousTab", "keymap.previous_tab"),
+    ("NextPage", "keymap.next_page"),
+    ("PreviousPage", "keymap.previous_page"),
+];
+
+fn binding(keystrokes: &str, action: &str) -> Option<KeyBinding> {
+    let context = Some(CONTEXT);
+    Some(match action {
+        "FocusNext" => KeyBinding::new(keystrokes, FocusNext, context),
+        "FocusPrevious" => KeyBinding::new(keystrokes, FocusPrevious, context),
+        "ToggleCommandPalette" => KeyBinding::new(keystrokes, ToggleCommandPalette, context),
+        "ShowKeyboardShortcuts" => KeyBinding::new(keystrokes, ShowKeyboardShortcuts, context),
+        "GoBack" => KeyBinding::new(keystrokes, GoBack, context),
+        "Undo" => KeyBinding::new(keystrokes, Undo, context),
+        "Redo" => KeyBinding::new(keystrokes, Redo, context),
+        "ToggleTheme" => KeyBinding::new(keystrokes, ToggleTheme, context),
+        "OpenDialog" => KeyBinding::new(keystrokes, OpenDialog, context),
+        "OpenSheet" => KeyBinding::new(keystrokes, OpenSheet, context),
+        "PushNotification" => KeyBinding::new(keystrokes, PushNotification, context),
+        "ResetState" => KeyBinding::new(keystrokes, ResetState, context),
+        "ToggleSidebar" => KeyBinding::new(keystrokes, ToggleSidebar, context),
+        "ToggleSidebarSide" => KeyBinding::new(keystrokes, ToggleSidebarSide, context),
+        "NextTab" => KeyBinding::new(keystrokes, NextTab, context),
+        "PreviousTab" => KeyBinding::new(keystrokes, PreviousTab, context),
+        "NextPage" => KeyBinding::new(keystrokes, NextPage, context),
+        "PreviousPage" => KeyBinding::new(keystrokes, PreviousPage, context),
+        _ => return None,
+    })
+}
+
+/// Parses each space-separated keystroke of a binding, e.g. `"ctrl-k ctrl-s"`.
+fn parse_keystrokes(keystrokes: &str) -> Option<Vec<Keystroke>> {
+    keystrokes
+        .split_whitespace()
+        .map(|keystroke| Keystroke::parse(keystroke).ok())
+        .collect::<Option<Vec<_>>>()
+        .filter(|keystrokes| !keystrokes.is_empty())
+}
+
+fn parse_keymap(source: &str) -> anyhow::Result<HashMap<String, Option<String>>> {
+    Ok(serde_json::from_str(source)?)
+}
+
+/// A problem with the user's keymap, listed in the shortcuts sheet.
+#[derive(Debug, PartialEq)]
+enum KeymapError {
+    Parse(String),
+    InvalidKeystroke(String),
+    UnknownAction(String),
+}
+
+impl KeymapError {
+    fn message(&self, cx: &App) -> SharedString {
+        match self {
+            KeymapError::Parse(error) => t_args(cx, "keymap.error_parse", &[("error", error)]),
+            KeymapError::InvalidKeystroke(keystrokes) => {
+                t_args(cx, "keymap.error_keystroke", &[("keystrokes", keystrokes)])
+            }
+            KeymapError::UnknownAction(action) => {
+                t_args(cx, "keymap.error_action", &[("action", action)])
+            }
+        }
+    }
+}
+
+/// The bindings in effect, keyed by keystroke.
+pub struct Keymap {
+    bindings: BTreeMap<String, String>,
+    user_loaded: bool,
+    errors: Vec<KeymapError>,
+}
+
+impl Global for Keymap {}
+
+impl Keymap {
+    fn empty() -> Self {
+        Self {
+            bindings: BTreeMap::new(),
+            user_loaded: false,
+            errors: Vec::new(),
+        }
+    }
+
+    fn load() -> Self {
+        let mut keymap = Self::empty();
+
+        match parse_keymap(DEFAULT_KEYMAP) {
+            Ok(entries) => keymap.apply(entries),
+            Err(err) => log::error!("invalid default keymap: {}", err),
+        }
+
+        match std::fs::read_to_string(USER_KEYMAP_PATH) {
+            Ok(source) => match parse_keymap(&source) {
+                Ok(entries) => {
+                    keymap.apply(entries);
+                    keymap.user_loaded = true;
+                }
+                Err(err) => {
+                    log::error!("invalid keymap {}: {}", USER_KEYMAP_PATH, err);
+                    keymap.errors.push(KeymapError::Parse(err.to_string()));
+                }
+            },
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => log::error!("failed to read {}: {}", USER_KEYMAP_PATH, err),
+        }
+
+        keymap
+    }
+
+    fn apply(&mut self, entries: HashMap<String, Option<String>>) {
+        for (keystrokes, action) in entries {
+            let Some(action) = action else {
+                self.bindings.remove(&keystrokes);
+                continue;
+            };
+            if parse_keystrokes(&keystrokes).is_none() {
+                self.errors.push(KeymapError::InvalidKeystroke(keystrokes));
+            } else if !ACTIONS.iter().any(|(name, _)| *name == action) {
+                self.errors.push(KeymapError::UnknownAction(action));
+            } else {
+                self.bindings.insert(keystrokes, action);
+            }
+        }
+    }
+
+    fn key_bindings(&self) -> Vec<KeyBinding> {
+        self.bindings
+            .iter()
+            .filter_map(|(keystrokes, action)| binding(keystrokes, action))
+            .collect()
+    }
+
+    /// Keystrokes bound to `action`, in keystroke order.
+    pub fn keystrokes_for<'a>(&'a self, action: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.bindings
+            .iter()
+            .filter(move |(_, bound)| *bound == action)
+            .map(|(keystrokes, _)| keystrokes.as_str())
+    }
+}
+
+pub fn init(cx: &mut App) {
+    let keymap = Keymap::load();
+    cx.bind_keys(keymap.key_bindings());
+    cx.set_global(keymap);
+}
+
+/// The first keystroke bound to `action`, for showing as a hint.
+pub fn keystroke_for(action: &str, cx: &App) -> Option<Keystroke> {
+    cx.global::<Keymap>()
+        .keystrokes_for(action)
+        .find_map(|keystrokes| parse_keystrokes(keystrokes)?.into_iter().next())
+}
+
+fn render_keystrokes(keystrokes: &str) -> impl IntoElement {
+    h_flex().gap_1().children(
+        parse_keystrokes(keystrokes)
+            .unwrap_or_default()
+            .into_iter()
+            .map(Kbd::new),
+    )
+}
+
+pub fn open_shortcuts_sheet(window: &mut Window, cx: &mut App) {
+    window.defer(cx, |window, cx| {
+        window.open_sheet(cx, |sheet, _, cx| {
+            let keymap = cx.global::<Keymap>();
+            let source = if keymap.user_loaded {
+                t_args(cx, "keymap.source_user", &[("path", &USER_KEYMAP_PATH)])
+            } else {
+                t_args(cx, "keymap.source_default", &[("path", &USER_KEYMAP_PATH)])
+            };
+
+            sheet.title(t(cx, "keymap.title")).child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(source),
+                    )
+                    .children(keymap.errors.iter().map(|error| {
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().danger)
+                            .child(error.message(cx))
+                    }))
+                    .children(ACTIONS.iter().filter_map(|(action, label)| {
+                        let keystrokes = keymap.keystrokes_for(action).collect::<Vec<_>>();
+                        (!keystrokes.is_empty()).then(|| {
+                            h_flex()
+                                .gap_3()
+                                .py_1()
+                                .justify_between()
+                                .border_b_1()
+                                .border_color(cx.theme().border)
+                                .child(t(cx, label))
+                                .child(
+                                    h_flex()
+                                        .gap_2()
+                                        .children(keystrokes.into_iter().map(render_keystrokes)),
+                                )
+                        })
+                    })),
+            )
+        });
+    });
+}
+
+impl ComponentGallery {
+    pub(crate) fn toggle_sidebar(
+        &mut self,
+        _: &ToggleSidebar,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.apply(
+            Change::SidebarCollapsed(!self.sidebar_collapsed),
+            window,
+            cx,
+        );
+    }
+
+    pub(crate) fn toggle_sidebar_side(
+        &mut self,
+        _: &ToggleSidebarSide,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.apply(Change::SidebarRight(!self.sidebar_side_right), window, cx);
+    }
+
+    pub(crate) fn next_tab(&mut self, _: &NextTab, window: &mut Window, cx: &mut Context<Self>) {
+        let ix = (self.tab_index + 1) % self.tabs.len();
+        self.apply(Change::Tab(ix), window, cx);
+    }
+
+    pub(crate) fn previous_tab(
+        &mut self,
+        _: &PreviousTab,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let ix = (self.tab_index + self.tabs.len() - 1) % self.tabs.len();
+        self.apply(Change::Tab(ix), window, cx);
+    }
+
+    pub(crate) fn next_page(&mut self, _: &NextPage, window: &mut Window, cx: &mut Context<Self>) {
+        let page = (self.pagination_page + 1).min(self.table_page_count(cx));
+        self.apply(Change::Page(page), window, cx);
+    }
+
+    pub(crate) fn previous_page(
+        &mut self,
+        _: &PreviousPage,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let page = self.pagination_page.saturating_sub(1).max(1);
+        self.apply(Change::Page(page), window, cx);
+    }
+
+    pub(crate) fn show_keyboard_shortcuts(
+        &mut self,
+        _: &ShowKeyboardShortcuts,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        open_shortcuts_sheet(window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(pairs: &[(&str, Option<&str>)]) -> HashMap<String, Option<String>> {
+        pairs
+            .iter()
+            .map(|(keystrokes, action)| (keystrokes.to_string(), action.map(str::to_string)))
+            .collect()
+    }
+
+    fn defaults() -> Keymap {
+        let mut keymap = Keymap::empty();
+        keymap.apply(parse_keymap(DEFAULT_KEYMAP).unwrap());
+        keymap
+    }
+
+    #[test]
+    fn default_keymap_binds_only_known_actions() {
+        let keymap = defaults();
+        assert_eq!(keymap.errors, vec![]);
+        assert_eq!(keymap.key_bindings().len(), keymap.bindings.len());
+    }
+
+    #[test]
+    fn every_action_has_a_binding() {
+        for (name, _) in ACTIONS {
+            let binding =
+                binding("ctrl-a", name).unwrap_or_else(|| panic!("{} has no binding", name));
+            assert!(
+                binding.action().name().ends_with(&format!("::{}", name)),
+                "{} binds {}",
+                name,
+                binding.action().name()
+            );
+        }
+        assert!(binding("ctrl-a", "Missing").is_none());
+    }
+
+    #[test]
+    fn null_unbinds_a_default() {
+        let mut keymap = defaults();
+        keymap.apply(entries(&[("ctrl-k", None)]));
+        assert_eq!(
+            keymap
+                .keystrokes_for("ToggleCommandPalette")
+                .collect::<Vec<_>>(),
+            vec!["cmd-k"]
+        );
+        assert_eq!(keymap.errors, vec![]);
+    }
+
+    #[test]
+    fn user_entries_override_defaults() {
+        let mut keymap = defaults();
+        keymap.apply(entries(&[("ctrl-b", Some("ToggleTheme"))]));
+        assert_eq!(keymap.bindings["ctrl-b"], "ToggleTheme");
+        assert_eq!(keymap.keystrokes_for("ToggleSidebar").count(), 0);
+    }
+
+    #[test]
+    fn invalid_entries_are_reported_and_skipped() {
+        let mut keymap = defaults();
+        let before = keymap.bindings.len();
+        keymap.apply(entries(&[("ctrl-foo-k", Some("Undo"))]));
+        keymap.apply(entries(&[("ctrl-y", Some("Frobnicate"))]));
+
+        assert_eq!(
+            keymap.errors,
+            vec![
+                KeymapError::InvalidKeystroke("ctrl-foo-k".into()),
+                KeymapError::UnknownAction("Frobnicate".into()),
+            ]
+        );
+        assert_eq!(keymap.bindings.len(), before);
+    }
+}
@@ -1,47 +1,95 @@
+use std::collections::HashSet;
 use std::rc::Rc;
 
 use gpui::{
-    div, prelude::*, px, size, App, Application, Bounds, Context, Entity, SharedString, Window,
-    WindowBounds, WindowOptions,
+    div, prelude::*, px, size, App, Application, Bounds, Context, Entity, FocusHandle,
+    ScrollHandle, SharedString, Subscription, Task, WeakEntity, Window, WindowBounds,
+    WindowOptions,
 };
 use gpui_component::{
     color_picker::{ColorPickerEvent, ColorPickerState},
-    date_picker::DatePickerState,
-    input::InputState,
+    date_picker::{DatePickerEvent, DatePickerState},
+    h_flex,
+    input::{self, InputEvent, InputState},
     list::ListState,
-    scroll::ScrollableElement as _,
-    select::SelectState,
+    menu::{ContextMenuExt as _, PopupMenuItem},
+    scroll::{ScrollableElement as _, ScrollbarAxis},
+    select::{SelectEvent, SelectState},
     slider::{SliderEvent, SliderState},
     table::TableState,
     tree::TreeState,
-    v_flex, ActiveTheme as _, Root, StyledExt as _, VirtualListScrollHandle,
+    v_flex, ActiveTheme as _, IndexPath, Root, StyledExt as _, Theme, VirtualListScrollHandle,
 };
-use gpui_component_assets::Assets as ComponentAssets;
 
 use log::LevelFilter;
 use ohos_hilog_binding::log::Config;
 use openharmony_ability::OpenHarmonyApp;
 
+mod assets;
+mod clipboard;
+mod command_palette;
+mod dialogs;
+mod focus;
+mod history;
+mod i18n;
+mod inspector;
+mod jobs;
+mod keymap;
+mod loading;
+mod notifications;
+mod panels;
+mod persistence;
+mod rating;
+mod router;
 mod sections;
+mod sheets;
+mod shell;
+#[cfg(test)]
+mod test_support;
+mod todo;
+mod wizard;
+
+use command_palette::Command;
+use i18n::{t, Locale};
+use router::{Demo, Route};
+use sections::Section;
 
 // On non-OHOS platforms, we don't need these imports
 
 pub(crate) struct ComponentGallery {
+    focus_handle: FocusHandle,
+    scroll_handle: ScrollHandle,
+    pub(crate) focus_rings: focus::FocusRings,
+    pub(crate) language_select: Entity<SelectState<Vec<SharedString>>>,
     pub(crate) input_state: Entity<InputState>,
     pub(crate) textarea_state: Entity<InputState>,
     pub(crate) select_state: Entity<SelectState<Vec<SharedString>>>,
     pub(crate) date_picker: Entity<DatePickerState>,
     pub(crate) color_picker: Entity<ColorPickerState>,
+    pub(crate) button_group_index: usize,
     pub(crate) checkbox_checked: bool,
     pub(crate) switch_on: bool,
     pub(crate) radio_checked: bool,
     pub(crate) rating_value: usize,
+    pub(crate) half_rating: f32,
+    pub(crate) heart_rating: f32,
+    pub(crate) review_table: Entity<TableState<sections::controls::ReviewTableDelegate>>,
     pub(crate) stepper_step: usize,
+    pub(crate) wizard: wizard::Wizard,
     pub(crate) pagination_page: usize,
     pub(crate) tab_index: usize,
+    pub(crate) tabs: sections::navigation::TabPages,
     pub(crate) collapsible_open: bool,
+    /// Indices of the open accordion items, in order.
+    pub(crate) accordion_open: Vec<usize>,
+    pub(crate) accordion_multiple: bool,
     pub(crate) slider_state: Entity<SliderState>,
     pub(crate) slider_value: f32,
+    pub(crate) slider_input: Entity<InputState>,
+    /// Message key of the numeric input's validation error, if any.
+    pub(crate) slider_input_error: Option<&'static str>,
+    pub(crate) range_slider: Entity<SliderState>,
+    pub(crate) vertical_slider: Entity<SliderState>,
     pub(crate) progress_value: f32,
     pub(crate) form_name: Entity<InputState>,
     pub(crate) form_email: Entity<InputState>,
@@ -50,13 +98,39 @@ pub(crate) struct ComponentGallery {
     pub(crate) form_subscribe: bool,
     pub(crate) list_state: Entity<ListState<sections::advanced::SimpleListDelegate>>,
     pub(crate) table_state: Entity<TableState<sections::advanced::SimpleTableDelegate>>,
+    pub(crate) table_date_range: Option<(chrono::NaiveDate, chrono::NaiveDate)>,
     pub(crate) tree_state: Entity<TreeState>,
     pub(crate) virtual_items: Vec<SharedString>,
     pub(crate) virtual_sizes: Rc<Vec<gpui::Size<gpui::Pixels>>>,
     pub(crate) virtual_scroll: VirtualListScrollHandle,
     pub(crate) sidebar_collapsed: bool,
     pub(crate) sidebar_side_right: bool,
+    /// Slugs of the expanded sidebar groups.
+    pub(crate) sidebar_groups: Vec<String>,
+    pub(crate) panels: panels::PanelLayout,
     pub(crate) menu_message: SharedString,
+    pub(crate) markdown_source: Entity<InputState>,
+    pub(crate) markdown_preview: SharedString,
+    pub(crate) markdown_stats: sections::markdown::MarkdownStats,
+    pub(crate) markdown_tab: usize,
+    markdown_debounce: Task<()>,
+    /// What was last written to disk, to tell when a save is due.
+    saved_state: persistence::GalleryState,
+    save_task: Task<()>,
+    pub(crate) docs: sections::docs::DocsViewer,
+    history: history::History,
+    pub(crate) greeting_name: Option<SharedString>,
+    sheet_stack: Entity<sheets::SheetStack>,
+    pub(crate) router: router::Router,
+    pub(crate) todo: todo::TodoList,
+    inspector: inspector::Inspector,
+    jobs: jobs::Jobs,
+    loader: loading::DataLoader,
+    disabled_cards: HashSet<&'static str>,
+    recent_commands: Vec<Command>,
+    /// The open command palette, if any, so toggling only closes that dialog.
+    palette: WeakEntity<command_palette::CommandPalette>,
+    palette_subscription: Option<Subscription>,
     _subscriptions: Vec<gpui::Subscription>,
 }
 
@@ -66,99 +140,226 @@ impl ComponentGallery {
             sections::advanced::DemoSettings::default(),
         );
 
-        let input_state = cx.new(|cx| InputState::new(window, cx).placeholder("Type here..."));
+        // Locale and theme come first so everything below is built with them.
+        let saved = persistence::GalleryState::load(persistence::state_path(cx).as_deref());
+        i18n::set_locale(saved.locale(), cx);
+        Theme::change(saved.theme_mode(), Some(window), cx);
+
+        let language_select = cx.new(|cx| {
+            let locale = i18n::locale(cx);
+            SelectState::new(
+                Locale::ALL
+                    .iter()
+                    .map(|locale| SharedString::from(locale.native_name()))
+                    .collect::<Vec<_>>(),
+                Locale::ALL
+                    .iter()
+                    .position(|l| *l == locale)
+                    .map(IndexPath::new),
+                window,
+                cx,
+            )
+        });
+
+        let input_state =
+            cx.new(|cx| InputState::new(window, cx).placeholder(t(cx, "forms.input_placeholder")));
         let textarea_state = cx.new(|cx| {
             InputState::new(window, cx)
                 .multi_line(true)
                 .rows(4)
-                .placeholder("Write something...")
+                .placeholder(t(cx, "forms.textarea_placeholder"))
         });
         let select_state = cx.new(|cx| {
-            SelectState::new(
-                vec![
-                    SharedString::from("Option A"),
-                    SharedString::from("Option B"),
-                    SharedString::from("Option C"),
-                ],
-                None,
-                window,
-                cx,
-            )
-            .searchable(true)
+            SelectState::new(sections::forms::select_options(cx), None, window, cx).searchable(true)
+        });
+        let date_picker = cx.new(|cx| {
+            DatePickerState::range(window, cx)
+                .number_of_months(2)
+                .disabled_matcher(sections::advanced::date_range_matcher())
         });
-        let date_picker = cx.new(|cx| DatePickerState::new(window, cx));
         let color_picker = cx.new(|cx| ColorPickerState::new(window, cx));
         let slider_state = cx.new(|_| {
+            SliderState::new()
+                .min(sections::controls::VOLUME_MIN)
+                .max(sections::controls::VOLUME_MAX)
+                .default_value(saved.slider_value)
+                .step(sections::controls::VOLUME_STEP)
+        });
+        let slider_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .default_value(sections::controls::format_volume(saved.slider_value))
+        });
+        let range_slider = cx.new(|_| {
+            SliderState::new()
+                .min(0.)
+                .max(1000.)
+                .default_value(200. ..800.)
+                .step(10.)
+        });
+        let vertical_slider = cx.new(|_| {
             SliderState::new()
                 .min(0.)
                 .max(100.)
-                .default_value(35.)
-                .step(1.)
+                .default_value(60.)
+                .step(5.)
         });
 
-        let form_name = cx.new(|cx| InputState::new(window, cx).placeholder("Name"));
-        let form_email = cx.new(|cx| InputState::new(window, cx).placeholder("Email"));
-        let form_role = cx.new(|cx| {
-            SelectState::new(
-                vec![
-                    SharedString::from("Admin"),
-                    SharedString::from("Editor"),
-                    SharedString::from("Viewer"),
-                ],
-                None,
-                window,
-                cx,
-            )
-        });
+        let form_name = cx.new(|cx| InputState::new(window, cx).placeholder(t(cx, "forms.name")));
+        let form_email = cx.new(|cx| InputState::new(window, cx).placeholder(t(cx, "forms.email")));
+        let form_role =
+            cx.new(|cx| SelectState::new(sections::forms::role_options(cx), None, window, cx));
         let form_notes = cx.new(|cx| {
             InputState::new(window, cx)
                 .multi_line(true)
                 .rows(3)
-                .placeholder("Notes")
+                .placeholder(t(cx, "forms.notes"))
         });
 
         let list_state =
             cx.new(|cx| ListState::new(sections::advanced::SimpleListDelegate::new(), window, cx));
-        let table_state = cx
-            .new(|cx| TableState::new(sections::advanced::SimpleTableDelegate::new(), window, cx));
+        let table_state = cx.new(|cx| {
+            TableState::new(
+                sections::advanced::SimpleTableDelegate::new(chrono::Local::now().date_naive()),
+                window,
+                cx,
+            )
+        });
+        let review_table = cx
+            .new(|cx| TableState::new(sections::controls::ReviewTableDelegate::new(), window, cx));
         let tree_state =
             cx.new(|cx| TreeState::new(cx).items(sections::advanced::sample_tree_items()));
 
+        let markdown_source = cx.new(|cx| {
+            InputState::new(window, cx)
+                .multi_line(true)
+                .rows(12)
+                .default_value(sections::markdown::SAMPLE)
+        });
+
         let virtual_items = (1..=40)
-            .map(|ix| SharedString::from(format!("Row {}", ix)))
+            .map(|ix| SharedString::from(ix.to_string()))
             .collect::<Vec<_>>();
         let virtual_sizes = Rc::new(vec![size(px(1.), px(32.)); virtual_items.len()]);
         let virtual_scroll = VirtualListScrollHandle::new();
 
-        let mut _subscriptions = vec![cx.subscribe(&slider_state, |this, _, ev, cx| {
-            let SliderEvent::Change(value) = ev;
-            this.slider_value = value.start();
-            this.progress_value = (this.slider_value / 100.0).clamp(0.0, 1.0) * 100.0;
-            cx.notify();
-        })];
+        let mut _subscriptions = vec![cx.subscribe_in(
+            &slider_state,
+            window,
+            |this, _, ev: &SliderEvent, window, cx| {
+                let SliderEvent::Change(value) = ev;
+                let before = history::Change::Slider(this.slider_value);
+                this.slider_value = value.start();
+                this.progress_value = (this.slider_value / 100.0).clamp(0.0, 1.0) * 100.0;
+                this.history
+                    .record(before, history::Change::Slider(this.slider_value));
+                this.sync_slider_input(window, cx);
+                cx.notify();
+            },
+        )];
+
+        _subscriptions.push(cx.subscribe_in(
+            &slider_input,
+            window,
+            |this, _, ev: &InputEvent, window, cx| this.on_slider_input(ev, window, cx),
+        ));
+        for slider in [&range_slider, &vertical_slider] {
+            _subscriptions.push(cx.subscribe(slider, |_, _, _: &SliderEvent, cx| cx.notify()));
+        }
 
         _subscriptions.push(cx.subscribe(&color_picker, |_, _, ev, cx| {
             let ColorPickerEvent::Change(_) = ev;
             cx.notify();
         }));
 
-        let view = Self {
+        _subscriptions.push(cx.subscribe(&date_picker, |this, _, ev, cx| {
+            let DatePickerEvent::Change(date) = ev;
+            this.filter_table_by_date(date, cx);
+        }));
+
+        _subscriptions.push(cx.subscribe_in(
+            &language_select,
+            window,
+            |this, _, ev: &SelectEvent<Vec<SharedString>>, window, cx| {
+                let SelectEvent::Confirm(Some(name)) = ev else {
+                    return;
+                };
+                if let Some(locale) = Locale::ALL
+                    .iter()
+                    .find(|l| l.native_name() == name.as_ref())
+                {
+                    this.set_locale(*locale, window, cx);
+                }
+            },
+        ));
+
+        let todo = todo::TodoList::new(window, cx);
+        _subscriptions.push(cx.subscribe_in(
+            &todo.input,
+            window,
+            |this, _, ev: &InputEvent, window, cx| {
+                if let InputEvent::PressEnter { .. } = ev {
+                    this.add_todo(window, cx);
+                }
+            },
+        ));
+
+        _subscriptions.push(cx.subscribe(&markdown_source, |this, _, ev, cx| {
+            if let InputEvent::Change = ev {
+                this.schedule_markdown_preview(cx);
+            }
+        }));
+
+        _subscriptions
+            .push(cx.observe_global::<notifications::NotificationCenter>(|_, cx| cx.notify()));
+
+        _subscriptions
+            .push(cx.observe_global::<sections::advanced::DemoSettings>(|_, cx| cx.notify()));
+
+        // Every state change notifies the gallery, so that is when to save.
+        // The theme can also be switched from gpui-component's own controls.
+        _subscriptions.push(cx.observe_self(|this, cx| this.persist(cx)));
+        _subscriptions.push(cx.observe_global::<Theme>(|this, cx| this.persist(cx)));
+        // Stacked sheets draw gallery state from their own view.
+        _subscriptions
+            .push(cx.observe_self(|this, cx| this.sheet_stack.update(cx, |_, cx| cx.notify())));
+
+        let focus_handle = cx.focus_handle();
+        focus_handle.focus(window);
+        let gallery = cx.weak_entity();
+
+        let mut view = Self {
+            focus_handle,
+            scroll_handle: ScrollHandle::new(),
+            focus_rings: focus::FocusRings::new(cx),
+            language_select,
             input_state,
             textarea_state,
             select_state,
             date_picker,
             color_picker,
-            checkbox_checked: true,
-            switch_on: false,
-            radio_checked: true,
-            rating_value: 3,
-            stepper_step: 1,
-            pagination_page: 2,
+            button_group_index: 0,
+            checkbox_checked: saved.checkbox_checked,
+            switch_on: saved.switch_on,
+            radio_checked: saved.radio_checked,
+            rating_value: saved.rating_value,
+            half_rating: saved.half_rating,
+            heart_rating: saved.heart_rating,
+            review_table,
+            stepper_step: 0,
+            wizard: wizard::Wizard::new(window, cx),
+            pagination_page: 1,
             tab_index: 0,
-            collapsible_open: false,
+            tabs: sections::navigation::TabPages::new(window, cx),
+            collapsible_open: saved.collapsible_open,
+            accordion_open: saved.accordion_open.clone(),
+            accordion_multiple: saved.accordion_multiple,
             slider_state,
-            slider_value: 35.0,
-            progress_value: 35.0,
+            slider_value: saved.slider_value,
+            slider_input,
+            slider_input_error: None,
+            range_slider,
+            vertical_slider,
+            progress_value: saved.slider_value.clamp(0., 100.),
             form_name,
             form_email,
             form_role,
@@ -166,42 +367,183 @@ impl ComponentGallery {
             form_subscribe: true,
             list_state,
             table_state,
+            table_date_range: None,
             tree_state,
             virtual_items,
             virtual_sizes,
             virtual_scroll,
-            sidebar_collapsed: false,
-            sidebar_side_right: false,
-            menu_message: "Idle".into(),
+            sidebar_collapsed: saved.sidebar_collapsed,
+            sidebar_side_right: saved.sidebar_side_right,
+            sidebar_groups: saved.sidebar_groups.clone(),
+            panels: panels::PanelLayout::new(saved.panel_sizes.clone()),
+            menu_message: "menus.idle".into(),
+            markdown_source,
+            markdown_preview: sections::markdown::SAMPLE.into(),
+            markdown_stats: sections::markdown::analyze(sections::markdown::SAMPLE),
+            markdown_tab: 0,
+            markdown_debounce: Task::ready(()),
+            saved_state: saved,
+            save_task: Task::ready(()),
+            docs: sections::docs::DocsViewer::new(cx),
+            history: history::History::default(),
+            greeting_name: None,
+            sheet_stack: cx.new(|_| sheets::SheetStack::new(gallery)),
+            router: router::Router::default(),
+            todo,
+            inspector: inspector::Inspector::default(),
+            jobs: jobs::Jobs::default(),
+            loader: loading::DataLoader::default(),
+            disabled_cards: HashSet::new(),
+            recent_commands: Vec::new(),
+            palette: WeakEntity::new_invalid(),
+            palette_subscription: None,
             _subscriptions,
         };
+        view.reload_all_data(cx);
 
         view
     }
 
+    /// Switches the UI language and refreshes strings baked into child entities.
+    pub(crate) fn set_locale(
+        &mut self,
+        locale: Locale,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        i18n::set_locale(locale, cx);
+
+        for (state, key) in [
+            (&self.input_state, "forms.input_placeholder"),
+            (&self.textarea_state, "forms.textarea_placeholder"),
+            (&self.form_name, "forms.name"),
+            (&self.form_email, "forms.email"),
+            (&self.form_notes, "forms.notes"),
+            (&self.todo.input, "todo.placeholder"),
+            (&self.wizard.username, "wizard.username"),
+            (&self.wizard.password, "wizard.password"),
+        ]
+        .into_iter()
+        .chain(
+            self.tabs
+                .notes_inputs()
+                .map(|notes| (notes, "navigation.notes_placeholder")),
+        ) {
+            let placeholder = t(cx, key);
+            state.update(cx, |state, cx| {
+                state.set_placeholder(placeholder, window, cx)
+            });
+        }
+
+        let options = sections::forms::select_options(cx);
+        self.select_state
+            .update(cx, |state, cx| state.set_items(options, window, cx));
+        let roles = sections::forms::role_options(cx);
+        self.form_role
+            .update(cx, |state, cx| state.set_items(roles, window, cx));
+        self.table_state.update(cx, |state, cx| state.refresh(cx));
+
+        cx.notify();
+    }
+
+    /// Restores every demo control to its initial value and starts a fresh history.
+    pub(crate) fn reset_state(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.button_group_index = 0;
+        self.checkbox_checked = true;
+        self.switch_on = false;
+        self.radio_checked = true;
+        self.rating_value = 3;
+        self.half_rating = 3.5;
+        self.heart_rating = 4.;
+        self.tab_index = 0;
+        self.collapsible_open = false;
+        self.accordion_open = vec![0];
+        self.accordion_multiple = false;
+        self.form_subscribe = true;
+        self.sidebar_collapsed = false;
+        self.sidebar_side_right = false;
+        self.sidebar_groups = vec![shell::NavGroup::Components.slug().into()];
+        self.panels.reset();
+        self.menu_message = "menus.idle".into();
+        self.set_slider_value(35., window, cx);
+        self.set_table_page(1, cx);
+        self.wizard_restart(window, cx);
+        self.history.clear();
+        cx.notify();
+    }
+
+    /// Asks for confirmation, then resets the demo state.
+    pub(crate) fn confirm_reset(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let answer = dialogs::confirm(t(cx, "reset.title"), t(cx, "reset.message"), window, cx);
+        cx.spawn_in(window, async move |this, cx| {
+            if answer.await.is_some() {
+                this.update_in(cx, |this, window, cx| this.reset_state(window, cx))
+                    .ok();
+            }
+        })
+        .detach();
+    }
+
+    /// Wraps a demo in a titled card. The card's context menu can inspect it or
+    /// disable it, which greys it out and blocks input until re-enabled.
     pub(crate) fn card<'a>(
         &self,
-        title: impl Into<SharedString>,
+        title_key: &'static str,
         content: impl IntoElement + 'a,
         cx: &mut Context<Self>,
     ) -> impl IntoElement + 'a {
-        let title = title.into();
+        let disabled = self.disabled_cards.contains(title_key);
+        let compact = sections::advanced::DemoSettings::compact_density(cx);
+        let padding = if compact { px(8.) } else { px(16.) };
+        let view = cx.entity();
+
         v_flex()
-            .gap_3()
-            .p_4()
+            .relative()
+            .gap(if compact { px(8.) } else { px(12.) })
+            .p(padding)
             .w_full()
             .bg(cx.theme().muted)
             .rounded_lg()
             .border_1()
             .border_color(cx.theme().border)
+            .when(disabled, |this| this.opacity(0.5))
             .child(
                 div()
                     .text_sm()
                     .font_semibold()
                     .text_color(cx.theme().muted_foreground)
-                    .child(title),
+                    .child(t(cx, title_key)),
             )
             .child(content)
+            .map(|this| self.inspector.attach(title_key, this))
+            .when(disabled, |this| {
+                this.child(div().absolute().inset_0().occlude())
+            })
+            .context_menu(move |menu, window, cx| {
+                menu.link(t(cx, "menus.docs"), "https://gpui.rs")
+                    .separator()
+                    .item(PopupMenuItem::new(t(cx, "menus.inspect")).on_click(
+                        window.listener_for(&view, move |this, _, _, cx| {
+                            this.inspect(Some(title_key.into()), cx)
+                        }),
+                    ))
+                    .item(
+                        PopupMenuItem::new(if disabled {
+                            t(cx, "menus.enable")
+                        } else {
+                            t(cx, "menus.disable")
+                        })
+                        .on_click(window.listener_for(
+                            &view,
+                            move |this, _, _, cx| {
+                                if !this.disabled_cards.remove(title_key) {
+                                    this.disabled_cards.insert(title_key);
+                                }
+                                cx.notify();
+                            },
+                        )),
+                    )
+            })
     }
 }
 
@@ -213,35 +555,102 @@ impl Render for ComponentGallery {
         let dialog_layer = Root::render_dialog_layer(window, cx);
         let notification_layer = Root::render_notification_layer(window, cx);
 
-        let content = v_flex()
-            .w_full()
-            .mx_auto()
-            .max_w(px(960.))
-            .overflow_x_hidden()
-            .when(is_compact, |this| this.px_3().py_4().gap_3())
-            .when(!is_compact, |this| this.px_6().py_6().gap_4())
-            .child(sections::header::render(self, window, cx))
-            .child(sections::buttons::render(self, window, cx))
-            .child(sections::forms::render(self, window, cx))
-            .child(sections::feedback::render(self, window, cx))
-            .child(sections::controls::render(self, window, cx))
-            .child(sections::navigation::render(self, window, cx))
-            .child(sections::disclosure::render(self, window, cx))
-            .child(sections::data_display::render(self, window, cx))
-            .child(sections::overlays::render(self, window, cx))
-            .child(sections::advanced::render(self, window, cx));
+        let route = self.router.current();
+        self.inspector.begin_frame();
+        self.inspector.set_section(Section::Header);
+        let header = Section::Header.render(self, window, cx);
+        let mut pages = Vec::new();
+        if route != Route::Home {
+            pages.push(sections::navigation::render_page_bar(self, window, cx));
+        }
+        for section in Section::ALL
+            .into_iter()
+            .filter(|section| *section != Section::Header && route.shows(*section))
+        {
+            self.inspector.set_section(section);
+            pages.push(section.render(self, window, cx));
+        }
+        match route {
+            Route::Demo(Demo::Todo) => pages.push(todo::render(self, window, cx)),
+            Route::Settings => pages.push(sections::advanced::render_settings(self, cx)),
+            Route::Home | Route::Section(_) => {}
+        }
+        let page = self.animate_page(
+            v_flex()
+                .w_full()
+                .gap(if is_compact { px(12.) } else { px(16.) })
+                .children(
+                    pages
+                        .into_iter()
+                        .map(|page| div().w_full().max_w(px(960.)).mx_auto().child(page)),
+                ),
+        );
+        let inspector = self.render_inspector(window, cx);
+        // Compact layouts open the sidebar as a drawer from the header instead.
+        let sidebar = (!is_compact).then(|| {
+            let sidebar = shell::render_sidebar(self, false, cx);
+            self.focus_ring(focus::FocusTarget::Sidebar, sidebar, window, cx)
+                .h_full()
+        });
+        let (sidebar_left, sidebar_right) = if self.sidebar_side_right {
+            (None, sidebar)
+        } else {
+            (sidebar, None)
+        };
 
         v_flex()
             .size_full()
+            .track_focus(&self.focus_handle)
+            .key_context(focus::CONTEXT)
+            .on_action(cx.listener(Self::focus_next))
+            .on_action(cx.listener(Self::focus_previous))
+            .on_action(cx.listener(Self::toggle_command_palette))
+            .on_action(cx.listener(Self::toggle_theme))
+            .on_action(cx.listener(Self::open_dialog))
+            .on_action(cx.listener(Self::open_sheet))
+            .on_action(cx.listener(Self::push_notification))
+            .on_action(cx.listener(Self::reset))
+            .on_action(cx.listener(Self::toggle_sidebar))
+            .on_action(cx.listener(Self::toggle_sidebar_side))
+            .on_action(cx.listener(Self::next_tab))
+            .on_action(cx.listener(Self::previous_tab))
+            .on_action(cx.listener(Self::next_page))
+            .on_action(cx.listener(Self::previous_page))
+            .on_action(cx.listener(Self::show_keyboard_shortcuts))
+            .on_action(cx.listener(Self::undo))
+            .on_action(cx.listener(Self::redo))
+            .on_action(cx.listener(Self::go_back))
+            .capture_action(|_: &input::Copy, window, cx| clipboard::record_input_copy(window, cx))
+            .capture_action(|_: &input::Cut, window, cx| clipboard::record_input_copy(window, cx))
             .bg(cx.theme().background)
             .child(
-                v_flex()
+                h_flex()
                     .size_full()
                     .flex_1()
-                    .overflow_y_scrollbar()
-                    .child(content),
+                    .children(sidebar_left)
+                    .child(
+                        div()
+                            .size_full()
+                            .flex_1()
+                            .min_w_0()
+                            .child(
+                                v_flex()
+                                    .id("gallery")
+                                    .size_full()
+                                    .overflow_x_hidden()
+                                    .when(is_compact, |this| this.px_3().py_4().gap_3())
+                                    .when(!is_compact, |this| this.px_6().py_6().gap_4())
+                                    .track_scroll(&self.scroll_handle)
+                                    .overflow_y_scroll()
+                                    .child(div().w_full().max_w(px(960.)).mx_auto().child(header))
+                                    .child(page),
+                            )
+                            .scrollbar(&self.scroll_handle, ScrollbarAxis::Vertical),
+                    )
+                    .children(sidebar_right),
             )
             .children(sheet_layer)
+            .children(inspector)
             .children(dialog_layer)
             .children(notification_layer)
     }
@@ -255,25 +664,46 @@ pub fn openharmony_app(app: OpenHarmonyApp) {
     // Initialize and run GPUI application
     // The event loop is automatically integrated by the platform
     Application::new()
-        .with_assets(ComponentAssets)
+        .with_assets(assets::Assets)
         .with_ohos_app(app.clone())
         .run(move |cx: &mut App| {
             gpui_component::init(cx);
+            i18n::init(cx);
+            keymap::init(cx);
+            notifications::init(cx);
+            clipboard::init(clipboard::PlatformClipboard, cx);
+            persistence::init(Some(persistence::STATE_PATH.into()), cx);
             let info = inner_app.content_rect();
             let default_size = size(px(info.width as _), px(info.height as _));
             let bounds = Bounds::centered(None, default_size, cx);
 
-            cx.open_window(
-                WindowOptions {
-                    window_bounds: Some(WindowBounds::Windowed(bounds)),
-                    ..Default::default()
-                },
-                |window, cx| {
-                    let view = cx.new(|cx| ComponentGallery::new(window, cx));
-                    cx.new(|cx| Root::new(view, window, cx))
-                },
-            )
-            .unwrap();
+            let mut gallery = WeakEntity::new_invalid();
+            let window = cx
+                .open_window(
+                    WindowOptions {
+                        window_bounds: Some(WindowBounds::Windowed(bounds)),
+                        ..Default::default()
+                    },
+                    |window, cx| {
+                        let view = cx.new(|cx| ComponentGallery::new(window, cx));
+                        gallery = view.downgrade();
+                        cx.new(|cx| Root::new(view, window, cx))
+                    },
+                )
+                .unwrap();
+
+            // The system back gesture takes the same path as GoBack. Returning
+            // false lets the system handle it, which leaves the app.
+            let async_cx = cx.to_async();
+            inner_app.on_back_press(move || {
+                window
+                    .update(&mut async_cx.clone(), |_, window, cx| {
+                        gallery
+                            .update(cx, |gallery, cx| gallery.handle_back(window, cx))
+                            .unwrap_or(false)
+                    })
+                    .unwrap_or(false)
+            });
             cx.activate(true);
         });
 }
@@ -0,0 +1,269 @@
+use std::time::Duration;
+
+use gpui::{div, prelude::*, px, AnyElement, Context, Task};
+use gpui_component::{
+    alert::Alert,
+    button::{Button, ButtonVariants},
+    h_flex,
+    skeleton::Skeleton,
+    v_flex, ActiveTheme as _, Sizable,
+};
+
+use crate::i18n::t;
+use crate::ComponentGallery;
+
+/// Artificial delays the loading demo can pick from.
+pub const DELAYS: [Duration; 4] = [
+    Duration::ZERO,
+    Duration::from_millis(500),
+    Duration::from_millis(1500),
+    Duration::from_millis(3000),
+];
+
+/// The cards backed by a mock data source.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataSet {
+    List,
+    Table,
+    Tree,
+    Charts,
+}
+
+impl DataSet {
+    pub const ALL: [DataSet; 4] = [
+        DataSet::List,
+        DataSet::Table,
+        DataSet::Tree,
+        DataSet::Charts,
+    ];
+
+    fn index(self) -> usize {
+        match self {
+            DataSet::List => 0,
+            DataSet::Table => 1,
+            DataSet::Tree => 2,
+            DataSet::Charts => 3,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoadState {
+    Loading,
+    Loaded,
+    Failed,
+}
+
+/// Load state of every mock data source, plus the knobs that shape the next
+/// load: how long it takes and whether it fails.
+pub struct DataLoader {
+    states: [LoadState; 4],
+    tasks: [Task<()>; 4],
+    pub delay: Duration,
+    pub simulate_errors: bool,
+}
+
+impl Default for DataLoader {
+    fn default() -> Self {
+        Self {
+            states: [LoadState::Loading; 4],
+            tasks: std::array::from_fn(|_| Task::ready(())),
+            delay: DELAYS[2],
+            simulate_errors: false,
+        }
+    }
+}
+
+impl DataLoader {
+    pub fn state(&self, set: DataSet) -> LoadState {
+        self.states[set.index()]
+    }
+}
+
+/// Placeholder shaped like the data `set` renders.
+fn skeleton(set: DataSet) -> AnyElement {
+    let line = |width: f32| Skeleton::new().w(px(width)).h(px(14.));
+    match set {
+        DataSet::List => v_flex()
+            .gap_3()
+            .p_2()
+            .children([180., 140., 200., 120., 160.].map(line))
+            .into_any_element(),
+        DataSet::Table => v_flex()
+            .gap_3()
+            .children((0..6).map(|row| {
+                h_flex()
+                    .gap_4()
+                    .child(line(40.))
+                    .child(line(if row == 0 { 80. } else { 120. }))
+                    .child(line(80.))
+                    .child(line(100.))
+            }))
+            .into_any_element(),
+        DataSet::Tree => v_flex()
+            .gap_3()
+            .p_2()
+            .children(
+                [(0., 120.), (16., 100.), (16., 140.), (32., 90.), (0., 110.)]
+                    .map(|(indent, width)| div().pl(px(indent)).child(line(width))),
+            )
+            .into_any_element(),
+        DataSet::Charts => h_flex()
+            .h(px(160.))
+            .gap_3()
+            .items_end()
+            .children(
+                [60., 110., 80., 140., 100., 70., 120.]
+                    .map(|height| Skeleton::new().w(px(28.)).h(px(height))),
+            )
+            .into_any_element(),
+    }
+}
+
+impl ComponentGallery {
+    /// Starts loading `set` from its mock source, replacing any load in flight.
+    pub(crate) fn load_data(&mut self, set: DataSet, cx: &mut Context<Self>) {
+        let (delay, fail) = (self.loader.delay, self.loader.simulate_errors);
+        self.loader.states[set.index()] = LoadState::Loading;
+        self.loader.tasks[set.index()] = cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(delay).await;
+            this.update(cx, |this, cx| {
+                this.loader.states[set.index()] = if fail {
+                    LoadState::Failed
+                } else {
+                    LoadState::Loaded
+                };
+                cx.notify();
+            })
+            .ok();
+        });
+        cx.notify();
+    }
+
+    pub(crate) fn reload_all_data(&mut self, cx: &mut Context<Self>) {
+        for set in DataSet::ALL {
+            self.load_data(set, cx);
+        }
+    }
+
+    /// Shows `content` once `set` has loaded, a matching skeleton while it
+    /// loads, and an error with a Retry button if it failed.
+    pub(crate) fn loadable(
+        &self,
+        set: DataSet,
+        content: impl FnOnce(&mut Context<Self>) -> AnyElement,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        match self.loader.state(set) {
+            LoadState::Loaded => content(cx),
+            LoadState::Loading => skeleton(set),
+            LoadState::Failed => v_flex()
+                .gap_2()
+                .child(
+                    Alert::error(("load-error", set.index()), t(cx, "loading.error"))
+                        .title(t(cx, "loading.error_title")),
+                )
+                .child(
+                    Button::new(("load-retry", set.index()))
+                        .label(t(cx, "loading.retry"))
+                        .outline()
+                        .small()
+                        .on_click(cx.listener(move |this, _, _, cx| this.load_data(set, cx))),
+                )
+                .into_any_element(),
+        }
+    }
+
+    /// Buttons that pick the artificial delay for the next load.
+    pub(crate) fn render_loading_controls(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        h_flex()
+            .gap_2()
+            .flex_wrap()
+            .items_center()
+            .child(div().text_sm().child(t(cx, "loading.delay")))
+            .children(DELAYS.into_iter().enumerate().map(|(ix, delay)| {
+                Button::new(("load-delay", ix))
+                    .label(format!("{:.1}s", delay.as_secs_f32()))
+                    .small()
+                    .when(self.loader.delay == delay, |this| this.primary())
+                    .when(self.loader.delay != delay, |this| this.ghost())
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        this.loader.delay = delay;
+                        cx.notify();
+                    }))
+            }))
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(t(cx, "loading.hint")),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{Entity, TestAppContext, VisualTestContext};
+
+    use super::*;
+    use crate::test_support::open_gallery;
+
+    fn load(
+        gallery: &Entity<ComponentGallery>,
+        delay: Duration,
+        fail: bool,
+        cx: &mut VisualTestContext,
+    ) {
+        gallery.update(cx, |this, cx| {
+            this.loader.delay = delay;
+            this.loader.simulate_errors = fail;
+            this.load_data(DataSet::Table, cx);
+        });
+    }
+
+    fn wait(duration: Duration, cx: &mut VisualTestContext) {
+        cx.executor().advance_clock(duration);
+        cx.run_until_parked();
+    }
+
+    fn state(gallery: &Entity<ComponentGallery>, cx: &mut VisualTestContext) -> LoadState {
+        gallery.read_with(cx, |this, _| this.loader.state(DataSet::Table))
+    }
+
+    #[gpui::test]
+    fn data_shows_once_the_delay_has_passed(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        load(&gallery, DELAYS[1], false, cx);
+        wait(DELAYS[1] / 2, cx);
+        assert_eq!(state(&gallery, cx), LoadState::Loading);
+
+        wait(DELAYS[1] / 2, cx);
+        assert_eq!(state(&gallery, cx), LoadState::Loaded);
+    }
+
+    #[gpui::test]
+    fn retrying_a_failed_load_loads_again(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        load(&gallery, DELAYS[1], true, cx);
+        wait(DELAYS[1], cx);
+        assert_eq!(state(&gallery, cx), LoadState::Failed);
+
+        load(&gallery, DELAYS[1], false, cx);
+        assert_eq!(state(&gallery, cx), LoadState::Loading);
+        wait(DELAYS[1], cx);
+        assert_eq!(state(&gallery, cx), LoadState::Loaded);
+    }
+
+    #[gpui::test]
+    fn a_replaced_load_never_overwrites_the_newer_result(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        load(&gallery, DELAYS[3], false, cx);
+        load(&gallery, DELAYS[1], true, cx);
+        wait(DELAYS[1], cx);
+        assert_eq!(state(&gallery, cx), LoadState::Failed);
+
+        // The first load would have succeeded by now.
+        wait(DELAYS[3], cx);
+        assert_eq!(state(&gallery, cx), LoadState::Failed);
+    }
+}
@@ -0,0 +1,241 @@
+use chrono::{DateTime, Local};
+use gpui::{div, prelude::*, App, Global, SharedString, Window};
+use gpui_component::{
+    button::{Button, ButtonVariants},
+    h_flex,
+    notification::{Notification, NotificationType},
+    v_flex, ActiveTheme as _, Icon, IconName, Sizable, WindowExt as _,
+};
+
+use crate::i18n::t;
+
+/// How many past notifications the center keeps.
+const HISTORY_LIMIT: usize = 50;
+
+pub struct Entry {
+    id: usize,
+    kind: NotificationType,
+    message: SharedString,
+    at: DateTime<Local>,
+    read: bool,
+}
+
+/// Every notification the gallery has pushed, newest first.
+#[derive(Default)]
+pub struct NotificationCenter {
+    entries: Vec<Entry>,
+    next_id: usize,
+}
+
+impl Global for NotificationCenter {}
+
+impl NotificationCenter {
+    pub fn unread_count(&self) -> usize {
+        self.entries.iter().filter(|entry| !entry.read).count()
+    }
+
+    fn record(&mut self, kind: NotificationType, message: SharedString) {
+        self.entries.insert(
+            0,
+            Entry {
+                id: self.next_id,
+                kind,
+                message,
+                at: Local::now(),
+                read: false,
+            },
+        );
+        self.entries.truncate(HISTORY_LIMIT);
+        self.next_id += 1;
+    }
+
+    fn mark_read(&mut self, id: usize) {
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.id == id) {
+            entry.read = true;
+        }
+    }
+
+    fn mark_all_read(&mut self) {
+        for entry in &mut self.entries {
+            entry.read = true;
+        }
+    }
+}
+
+pub fn init(cx: &mut App) {
+    cx.set_global(NotificationCenter::default());
+}
+
+pub fn unread_count(cx: &App) -> usize {
+    cx.global::<NotificationCenter>().unread_count()
+}
+
+/// Shows a toast and records it in the notification center. All gallery
+/// notifications should go through here rather than `window.push_notification`.
+pub fn push(
+    kind: NotificationType,
+    message: impl Into<SharedString>,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let message = message.into();
+    cx.global_mut::<NotificationCenter>()
+        .record(kind, message.clone());
+    window.push_notification(
+        Notification::new()
+            .message(message)
+            .with_type(kind)
+            .autohide(true),
+        cx,
+    );
+}
+
+fn severity_icon(kind: NotificationType, cx: &App) -> Icon {
+    let (icon, color) = match kind {
+        NotificationType::Info => (IconName::Info, cx.theme().info),
+        NotificationType::Success => (IconName::CircleCheck, cx.theme().success),
+        NotificationType::Warning => (IconName::TriangleAlert, cx.theme().warning),
+        NotificationType::Error => (IconName::CircleX, cx.theme().danger),
+    };
+    Icon::new(icon).text_color(color)
+}
+
+pub fn open_center(window: &mut Window, cx: &mut App) {
+    window.defer(cx, |window, cx| {
+        window.open_sheet(cx, |sheet, _, cx| {
+            let center = cx.global::<NotificationCenter>();
+
+            let toolbar = h_flex()
+                .gap_2()
+                .child(
+                    Button::new("mark-all-read")
+                        .label(t(cx, "notifications.mark_all_read"))
+                        .outline()
+                        .small()
+                        .disabled(center.unread_count() == 0)
+                        .on_click(|_, window, cx| {
+                            cx.global_mut::<NotificationCenter>().mark_all_read();
+                            window.refresh();
+                        }),
+                )
+                .child(
+                    Button::new("clear-all")
+                        .label(t(cx, "notifications.clear_all"))
+                        .outline()
+                        .small()
+                        .disabled(center.entries.is_empty())
+                        .on_click(|_, window, cx| {
+                            cx.global_mut::<NotificationCenter>().entries.clear();
+                            window.refresh();
+                        }),
+                );
+
+            let list = v_flex()
+                .gap_2()
+                .when(center.entries.is_empty(), |this| {
+                    this.child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(t(cx, "notifications.empty")),
+                    )
+                })
+                .children(center.entries.iter().map(|entry| {
+                    let id = entry.id;
+                    h_flex()
+                        .gap_3()
+                        .p_2()
+                        .items_start()
+                        .rounded(cx.theme().radius)
+                        .when(!entry.read, |this| this.bg(cx.theme().accent))
+                        .child(severity_icon(entry.kind, cx))
+                        .child(
+                            v_flex()
+                                .flex_1()
+                                .gap_1()
+                                .child(div().text_sm().child(entry.message.clone()))
+                                .child(
+                                    div()
+                                        .text_xs()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(entry.at.format("%H:%M:%S").to_string()),
+                                ),
+                        )
+                        .when(!entry.read, |this| {
+                            this.child(
+                                Button::new(("mark-read", id))
+                                    .label(t(cx, "notifications.mark_read"))
+                                    .ghost()
+                                    .xsmall()
+                                    .on_click(move |_, window, cx| {
+                                        cx.global_mut::<NotificationCenter>().mark_read(id);
+                                        window.refresh();
+                                    }),
+                            )
+                        })
+                }));
+
+            sheet
+                .title(t(cx, "notifications.title"))
+                .child(v_flex().gap_3().child(toolbar).child(list))
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(center: &NotificationCenter) -> Vec<&str> {
+        center
+            .entries
+            .iter()
+            .map(|entry| entry.message.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn notifications_are_recorded_newest_first_and_unread() {
+        let mut center = NotificationCenter::default();
+        center.record(NotificationType::Info, "first".into());
+        center.record(NotificationType::Error, "second".into());
+
+        assert_eq!(messages(&center), vec!["second", "first"]);
+        assert_eq!(center.unread_count(), 2);
+    }
+
+    #[test]
+    fn history_keeps_only_the_newest_entries() {
+        let mut center = NotificationCenter::default();
+        for n in 0..HISTORY_LIMIT + 5 {
+            center.record(NotificationType::Info, n.to_string().into());
+        }
+
+        assert_eq!(center.entries.len(), HISTORY_LIMIT);
+        let messages = messages(&center);
+        assert_eq!(messages[0], (HISTORY_LIMIT + 4).to_string());
+        assert_eq!(messages[HISTORY_LIMIT - 1], "5");
+        assert_eq!(center.unread_count(), HISTORY_LIMIT);
+    }
+
+    #[test]
+    fn marking_read_lowers_the_unread_count() {
+        let mut center = NotificationCenter::default();
+        for message in ["a", "b", "c"] {
+            center.record(NotificationType::Success, message.into());
+        }
+        let first = center.entries[2].id;
+
+        center.mark_read(first);
+        center.mark_read(first);
+        assert_eq!(center.unread_count(), 2);
+        assert!(center.entries[2].read);
+
+        // Ids of entries that were dropped or never existed are ignored.
+        center.mark_read(center.next_id);
+        assert_eq!(center.unread_count(), 2);
+
+        center.mark_all_read();
+        assert_eq!(center.unread_count(), 0);
+    }
+}
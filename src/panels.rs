@@ -0,0 +1,392 @@
+use std::collections::{BTreeMap, HashMap};
+
+use gpui::{
+    div, prelude::*, px, AnyElement, Axis, Bounds, Context, DragMoveEvent, Empty, Pixels, Point,
+    Window,
+};
+use gpui_component::ActiveTheme as _;
+
+use crate::ComponentGallery;
+
+/// Handle thickness for pointers, and for fingers on compact layouts.
+const HANDLE: f32 = 8.;
+const TOUCH_HANDLE: f32 = 20.;
+
+/// A panel of a [`SplitGroup`]. `id` is what its size is saved under.
+pub struct PanelSpec {
+    pub id: &'static str,
+    pub size: f32,
+    pub min: f32,
+    pub max: f32,
+    /// Whether dragging below half of `min` collapses the panel to zero.
+    pub collapsible: bool,
+    /// The one panel per group that takes the remaining space instead of a size.
+    pub flex: bool,
+}
+
+impl PanelSpec {
+    pub const fn sized(id: &'static str, size: f32, min: f32, max: f32) -> Self {
+        Self {
+            id,
+            size,
+            min,
+            max,
+            collapsible: true,
+            flex: false,
+        }
+    }
+
+    pub const fn flex(id: &'static str) -> Self {
+        Self {
+            id,
+            size: 0.,
+            min: 0.,
+            max: 0.,
+            collapsible: false,
+            flex: true,
+        }
+    }
+
+    /// Brings `size` within bounds, collapsing the panel below half its minimum.
+    fn clamp(&self, size: f32) -> f32 {
+        if self.collapsible && size < self.min / 2. {
+            0.
+        } else {
+            size.clamp(self.min, self.max)
+        }
+    }
+}
+
+/// Panels laid out along `axis` with a drag handle between each pair.
+/// Groups nest by passing a group's element as another group's panel.
+/// gpui-component's resizable panels can't collapse a panel or hand their
+/// sizes back for saving, hence this instead.
+pub struct SplitGroup {
+    pub id: &'static str,
+    pub axis: Axis,
+    pub panels: &'static [PanelSpec],
+}
+
+impl SplitGroup {
+    fn flex_index(&self) -> usize {
+        self.panels
+            .iter()
+            .position(|panel| panel.flex)
+            .unwrap_or(self.panels.len())
+    }
+
+    /// The panel a handle resizes: the sized one of the two it sits between.
+    fn resized_by_handle(&self, handle: usize) -> usize {
+        if handle < self.flex_index() {
+            handle
+        } else {
+            handle + 1
+        }
+    }
+}
+
+/// Panel sizes by id. Only sizes that were changed are stored, so defaults
+/// can change without overriding what the user chose.
+#[derive(Default)]
+pub struct PanelLayout {
+    pub sizes: BTreeMap<String, f32>,
+    /// Size to return to when a collapsed panel is shown again.
+    restore: HashMap<&'static str, f32>,
+}
+
+impl PanelLayout {
+    pub fn new(sizes: BTreeMap<String, f32>) -> Self {
+        Self {
+            sizes,
+            restore: HashMap::new(),
+        }
+    }
+
+    /// The panel's size. Saved sizes are clamped like dragged ones, since
+    /// the file may be edited or predate the panel's current bounds.
+    pub fn size(&self, panel: &PanelSpec) -> f32 {
+        self.sizes
+            .get(panel.id)
+            .map_or(panel.size, |size| panel.clamp(*size))
+    }
+
+    pub fn is_collapsed(&self, panel: &PanelSpec) -> bool {
+        panel.collapsible && self.size(panel) == 0.
+    }
+
+    fn set_size(&mut self, panel: &PanelSpec, size: f32) {
+        self.sizes.insert(panel.id.into(), panel.clamp(size));
+    }
+
+    /// Collapses the panel, or brings it back at the size it had before.
+    pub fn toggle_collapsed(&mut self, panel: &PanelSpec) {
+        if !panel.collapsible {
+            return;
+        }
+        if self.is_collapsed(panel) {
+            let size = self.restore.remove(panel.id).unwrap_or(panel.size);
+            self.set_size(panel, size);
+        } else {
+            self.restore.insert(panel.id, self.size(panel));
+            self.sizes.insert(panel.id.into(), 0.);
+        }
+    }
+
+    pub fn reset(&mut self) {
+        self.sizes.clear();
+        self.restore.clear();
+    }
+}
+
+/// Payload of a handle drag, telling groups which handle moved.
+#[derive(Clone, Copy)]
+struct PanelDrag {
+    group: &'static str,
+    handle: usize,
+    thickness: f32,
+}
+
+impl Render for PanelDrag {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
+fn along(axis: Axis, point: Point<Pixels>) -> f32 {
+    f32::from(match axis {
+        Axis::Horizontal => point.x,
+        Axis::Vertical => point.y,
+    })
+}
+
+impl ComponentGallery {
+    /// Sizes the panel behind `drag` so its edge follows the pointer.
+    fn drag_panel(
+        &mut self,
+        group: &'static SplitGroup,
+        drag: PanelDrag,
+        position: Point<Pixels>,
+        bounds: Bounds<Pixels>,
+        cx: &mut Context<Self>,
+    ) {
+        let ix = group.resized_by_handle(drag.handle);
+        let pointer = along(group.axis, position);
+        let occupied = |panels: &[PanelSpec]| -> f32 {
+            panels
+                .iter()
+                .map(|panel| self.panels.size(panel) + drag.thickness)
+                .sum()
+        };
+        // Panels before the flex panel grow from the group's start, the ones
+        // after it from the end.
+        let size = if ix < group.flex_index() {
+            pointer
+                - along(group.axis, bounds.origin)
+                - occupied(&group.panels[..ix])
+                - drag.thickness / 2.
+        } else {
+            along(group.axis, bounds.bottom_right())
+                - pointer
+                - occupied(&group.panels[ix + 1..])
+                - drag.thickness / 2.
+        };
+        self.panels.set_size(&group.panels[ix], size);
+        cx.notify();
+    }
+
+    /// Lays out `children`, one per panel of `group`, with drag handles between them.
+    pub(crate) fn render_split(
+        &self,
+        group: &'static SplitGroup,
+        children: Vec<AnyElement>,
+        is_compact: bool,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let thickness = if is_compact { TOUCH_HANDLE } else { HANDLE };
+        let horizontal = group.axis == Axis::Horizontal;
+        let count = children.len();
+        let (track, hover, grip) = (
+            cx.theme().border.opacity(0.3),
+            cx.theme().accent,
+            cx.theme().muted_foreground.opacity(0.6),
+        );
+
+        let mut items = Vec::with_capacity(count * 2);
+        for (ix, (panel, child)) in group.panels.iter().zip(children).enumerate() {
+            let size = px(self.panels.size(panel));
+            items.push(
+                div()
+                    .overflow_hidden()
+                    .map(|this| match (panel.flex, horizontal) {
+                        (true, true) => this.flex_1().min_w_0().h_full(),
+                        (true, false) => this.flex_1().min_h_0().w_full(),
+                        (false, true) => this.flex_none().w(size).h_full(),
+                        (false, false) => this.flex_none().h(size).w_full(),
+                    })
+                    .child(child)
+                    .into_any_element(),
+            );
+            if ix + 1 == count {
+                break;
+            }
+
+            let drag = PanelDrag {
+                group: group.id,
+                handle: ix,
+                thickness,
+            };
+            items.push(
+                div()
+                    .id((group.id, ix))
+                    .flex_none()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .bg(track)
+                    .hover(|this| this.bg(hover))
+                    .map(|this| {
+                        if horizontal {
+                            this.w(px(thickness)).h_full().cursor_col_resize()
+                        } else {
+                            this.h(px(thickness)).w_full().cursor_row_resize()
+                        }
+                    })
+                    .on_drag(drag, |drag, _, _, cx| cx.new(|_| *drag))
+                    .child(div().rounded_full().bg(grip).map(|this| {
+                        if horizontal {
+                            this.w(px(4.)).h(px(28.))
+                        } else {
+                            this.w(px(28.)).h(px(4.))
+                        }
+                    }))
+                    .into_any_element(),
+            );
+        }
+
+        div()
+            .id(group.id)
+            .flex()
+            .size_full()
+            .map(|this| {
+                if horizontal {
+                    this.flex_row()
+                } else {
+                    this.flex_col()
+                }
+            })
+            .on_drag_move(
+                cx.listener(move |this, ev: &DragMoveEvent<PanelDrag>, _, cx| {
+                    let drag = *ev.drag(cx);
+                    // Nested groups see each other's drags; only handle our own.
+                    if drag.group == group.id {
+                        this.drag_panel(group, drag, ev.event.position, ev.bounds, cx);
+                    }
+                }),
+            )
+            .children(items)
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIDE: PanelSpec = PanelSpec::sized("side", 120., 64., 240.);
+    const FIXED: PanelSpec = PanelSpec {
+        collapsible: false,
+        ..PanelSpec::sized("fixed", 72., 40., 160.)
+    };
+
+    #[test]
+    fn dragging_below_half_the_minimum_collapses_the_panel() {
+        let mut layout = PanelLayout::default();
+        layout.set_size(&SIDE, 33.);
+        assert_eq!(layout.size(&SIDE), 64.);
+        assert!(!layout.is_collapsed(&SIDE));
+
+        layout.set_size(&SIDE, 31.);
+        assert_eq!(layout.size(&SIDE), 0.);
+        assert!(layout.is_collapsed(&SIDE));
+
+        layout.set_size(&FIXED, 0.);
+        assert_eq!(layout.size(&FIXED), 40.);
+        assert!(!layout.is_collapsed(&FIXED));
+    }
+
+    #[test]
+    fn toggling_a_collapsed_panel_restores_its_size() {
+        let mut layout = PanelLayout::default();
+        layout.set_size(&SIDE, 200.);
+        layout.toggle_collapsed(&SIDE);
+        assert!(layout.is_collapsed(&SIDE));
+
+        layout.toggle_collapsed(&SIDE);
+        assert_eq!(layout.size(&SIDE), 200.);
+
+        // Collapsed by dragging, there is no size to go back to.
+        layout.set_size(&SIDE, 0.);
+        layout.toggle_collapsed(&SIDE);
+        assert_eq!(layout.size(&SIDE), SIDE.size);
+
+        layout.toggle_collapsed(&FIXED);
+        assert_eq!(layout.size(&FIXED), FIXED.size);
+    }
+
+    #[test]
+    fn saved_sizes_are_clamped_to_the_panels_bounds() {
+        let saved = [("side", 900.), ("fixed", -5.)]
+            .into_iter()
+            .map(|(id, size)| (id.to_string(), size))
+            .collect();
+        let layout = PanelLayout::new(saved);
+        assert_eq!(layout.size(&SIDE), 240.);
+        assert_eq!(layout.size(&FIXED), 40.);
+
+        let layout = PanelLayout::new([("side".to_string(), -5.)].into_iter().collect());
+        assert!(layout.is_collapsed(&SIDE));
+
+        let mut layout = PanelLayout::default();
+        layout.set_size(&SIDE, 200.);
+        layout.reset();
+        assert_eq!(layout.size(&SIDE), SIDE.size);
+    }
+
+    #[test]
+    fn handles_resize_the_sized_panel_beside_them() {
+        const PANELS: [PanelSpec; 3] = [
+            PanelSpec::sized("left", 120., 64., 240.),
+            PanelSpec::flex("center"),
+            PanelSpec::sized("right", 120., 64., 240.),
+        ];
+        let group = SplitGroup {
+            id: "group",
+            axis: Axis::Horizontal,
+            panels: &PANELS,
+        };
+        assert_eq!(group.resized_by_handle(0), 0);
+        assert_eq!(group.resized_by_handle(1), 2);
+
+        const TRAILING_FLEX: [PanelSpec; 2] = [
+            PanelSpec::sized("top", 72., 40., 160.),
+            PanelSpec::flex("rest"),
+        ];
+        let group = SplitGroup {
+            id: "group",
+            axis: Axis::Vertical,
+            panels: &TRAILING_FLEX,
+        };
+        assert_eq!(group.resized_by_handle(0), 0);
+
+        const LEADING_FLEX: [PanelSpec; 2] = [
+            PanelSpec::flex("rest"),
+            PanelSpec::sized("bottom", 72., 40., 160.),
+        ];
+        let group = SplitGroup {
+            id: "group",
+            axis: Axis::Vertical,
+            panels: &LEADING_FLEX,
+        };
+        assert_eq!(group.resized_by_handle(0), 1);
+    }
+}
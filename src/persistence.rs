@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use gpui::{App, Context, Global};
+use gpui_component::{ActiveTheme as _, ThemeMode};
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::{self, Locale};
+use crate::shell::NavGroup;
+use crate::ComponentGallery;
+
+/// Where the app keeps gallery state between launches, next to the user keymap.
+pub const STATE_PATH: &str = "/data/storage/el2/base/files/gallery_state.json";
+
+/// The file gallery state is loaded from and saved to, or `None` to keep
+/// state in memory only, as tests do.
+pub struct StateFile(Option<PathBuf>);
+
+impl Global for StateFile {}
+
+pub fn init(path: Option<PathBuf>, cx: &mut App) {
+    cx.set_global(StateFile(path));
+}
+
+pub fn state_path(cx: &App) -> Option<PathBuf> {
+    cx.global::<StateFile>().0.clone()
+}
+
+/// How long state must stay unchanged before it is written, so dragging a
+/// slider doesn't write on every frame.
+const SAVE_DELAY: Duration = Duration::from_millis(500);
+
+/// Everything the gallery restores on launch. Fields missing from an older
+/// file take their default, so adding a field never invalidates saved state.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GalleryState {
+    pub locale: String,
+    pub dark_theme: bool,
+    pub checkbox_checked: bool,
+    pub switch_on: bool,
+    pub radio_checked: bool,
+    pub rating_value: usize,
+    pub half_rating: f32,
+    pub heart_rating: f32,
+    pub slider_value: f32,
+    pub collapsible_open: bool,
+    pub accordion_open: Vec<usize>,
+    pub accordion_multiple: bool,
+    pub sidebar_collapsed: bool,
+    pub sidebar_side_right: bool,
+    pub sidebar_groups: Vec<String>,
+    /// Resizable panel sizes by panel id.
+    pub panel_sizes: BTreeMap<String, f32>,
+}
+
+impl Default for GalleryState {
+    fn default() -> Self {
+        Self {
+            locale: Locale::En.code().into(),
+            dark_theme: false,
+            checkbox_checked: true,
+            switch_on: false,
+            radio_checked: true,
+            rating_value: 3,
+            half_rating: 3.5,
+            heart_rating: 4.,
+            slider_value: 35.,
+            collapsible_open: false,
+            accordion_open: vec![0],
+            accordion_multiple: false,
+            sidebar_collapsed: false,
+            sidebar_side_right: false,
+            sidebar_groups: vec![NavGroup::Components.slug().into()],
+            panel_sizes: BTreeMap::new(),
+        }
+    }
+}
+
+impl GalleryState {
+    /// Reads state saved at `path`, falling back to defaults if there is none
+    /// or it can't be parsed.
+    pub fn load(path: Option<&Path>) -> Self {
+        let Some(path) = path else {
+            return Self::default();
+        };
+        match std::fs::read_to_string(path) {
+            Ok(source) => match serde_json::from_str(&source) {
+                Ok(state) => state,
+                Err(err) => {
+                    log::error!("invalid gallery state {}: {}", path.display(), err);
+                    Self::default()
+                }
+            },
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(err) => {
+                log::error!("failed to read {}: {}", path.display(), err);
+                Self::default()
+            }
+        }
+    }
+
+    /// Writes to a temporary file first, so a crash mid-write never leaves a
+    /// truncated state file behind.
+    fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let tmp = path.with_extension("json.tmp");
+        std::fs::write(&tmp, serde_json::to_string_pretty(self)?)?;
+        std::fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    pub fn locale(&self) -> Locale {
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.code() == self.locale)
+            .unwrap_or(Locale::En)
+    }
+
+    pub fn theme_mode(&self) -> ThemeMode {
+        if self.dark_theme {
+            ThemeMode::Dark
+        } else {
+            ThemeMode::Light
+        }
+    }
+}
+
+impl ComponentGallery {
+    fn snapshot(&self, cx: &App) -> GalleryState {
+        GalleryState {
+            locale: i18n::locale(cx).code().into(),
+            dark_theme: cx.theme().mode.is_dark(),
+            checkbox_checked: self.checkbox_checked,
+            switch_on: self.switch_on,
+            radio_checked: self.radio_checked,
+            rating_value: self.rating_value,
+            half_rating: self.half_rating,
+            heart_rating: self.heart_rating,
+            slider_value: self.slider_value,
+            collapsible_open: self.collapsible_open,
+            accordion_open: self.accordion_open.clone(),
+            accordion_multiple: self.accordion_multiple,
+            sidebar_collapsed: self.sidebar_collapsed,
+            sidebar_side_right: self.sidebar_side_right,
+            sidebar_groups: self.sidebar_groups.clone(),
+            panel_sizes: self.panels.sizes.clone(),
+        }
+    }
+
+    /// Called whenever the gallery notifies: if the persisted state changed,
+    /// writes it once it has settled for [`SAVE_DELAY`].
+    pub(crate) fn persist(&mut self, cx: &mut Context<Self>) {
+        let state = self.snapshot(cx);
+        if state == self.saved_state {
+            return;
+        }
+        self.saved_state = state.clone();
+        let Some(path) = state_path(cx) else {
+            return;
+        };
+        self.save_task = cx.spawn(async move |_, cx| {
+            cx.background_executor().timer(SAVE_DELAY).await;
+            let result = cx
+                .background_spawn({
+                    let path = path.clone();
+                    async move { state.save(&path) }
+                })
+                .await;
+            if let Err(err) = result {
+                log::error!("failed to save {}: {}", path.display(), err);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh state file path in the system temp dir, unique to the test.
+    fn temp_state_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("gallery-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join("gallery_state.json")
+    }
+
+    #[test]
+    fn saved_state_loads_back_unchanged() {
+        let path = temp_state_path("round-trip");
+        let state = GalleryState {
+            locale: Locale::ZhCn.code().into(),
+            dark_theme: true,
+            rating_value: 5,
+            slider_value: 72.5,
+            accordion_open: vec![1, 2],
+            accordion_multiple: true,
+            sidebar_groups: vec![],
+            panel_sizes: BTreeMap::from([("left".into(), 240.)]),
+            ..GalleryState::default()
+        };
+
+        state.save(&path).unwrap();
+        assert_eq!(GalleryState::load(Some(&path)), state);
+        assert!(!path.with_extension("json.tmp").exists());
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn fields_missing_from_an_older_file_take_their_defaults() {
+        let state: GalleryState =
+            serde_json::from_str(r#"{ "dark_theme": true, "rating_value": 1 }"#).unwrap();
+
+        assert_eq!(
+            state,
+            GalleryState {
+                dark_theme: true,
+                rating_value: 1,
+                ..GalleryState::default()
+            }
+        );
+        assert_eq!(state.accordion_open, vec![0]);
+        assert!(!state.accordion_multiple);
+    }
+
+    #[test]
+    fn missing_or_unreadable_state_loads_defaults() {
+        let path = temp_state_path("invalid");
+        assert_eq!(GalleryState::load(Some(&path)), GalleryState::default());
+
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, "not json").unwrap();
+        assert_eq!(GalleryState::load(Some(&path)), GalleryState::default());
+        assert_eq!(GalleryState::load(None), GalleryState::default());
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
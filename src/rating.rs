@@ -0,0 +1,196 @@
+use std::rc::Rc;
+
+use gpui::{div, prelude::*, px, AnyElement, App, ElementId, Hsla, Pixels, SharedString, Window};
+use gpui_component::{h_flex, ActiveTheme as _, Icon, IconName};
+
+/// Number of icons shown.
+const MAX: usize = 5;
+
+type ChangeHandler = Rc<dyn Fn(&f32, &mut Window, &mut App)>;
+
+/// A star rating that, unlike gpui-component's `Rating`, takes fractional
+/// values: it can step in halves, display an average like 3.7 read-only, and
+/// use any icon and color. Tapping the current value clears it to 0.
+#[derive(IntoElement)]
+pub struct StarRating {
+    id: ElementId,
+    value: f32,
+    half: bool,
+    read_only: bool,
+    icon: IconName,
+    color: Option<Hsla>,
+    size: Pixels,
+    on_change: Option<ChangeHandler>,
+}
+
+impl StarRating {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            value: 0.,
+            half: false,
+            read_only: false,
+            icon: IconName::Star,
+            color: None,
+            size: px(20.),
+            on_change: None,
+        }
+    }
+
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Lets the left half of each icon select a half step.
+    pub fn half(mut self, half: bool) -> Self {
+        self.half = half;
+        self
+    }
+
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// Fill color of selected icons; defaults to the theme's warning color.
+    pub fn color(mut self, color: Hsla) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn icon_size(mut self, size: Pixels) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn on_change(mut self, handler: impl Fn(&f32, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// How much of icon `n` (1-based) a rating of `value` fills: 0, 1, or a
+/// fraction for half steps and averages.
+fn fill_fraction(value: f32, n: usize) -> f32 {
+    (value - (n - 1) as f32).clamp(0., 1.)
+}
+
+/// The value after tapping `target`: tapping the current value clears it.
+fn tapped_value(value: f32, target: f32) -> f32 {
+    if target == value {
+        0.
+    } else {
+        target
+    }
+}
+
+impl RenderOnce for StarRating {
+    fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+        let color = self.color.unwrap_or(cx.theme().warning);
+        let empty = cx.theme().muted_foreground.opacity(0.4);
+        let size = self.size;
+        let value = self.value;
+        let handler = self.on_change.filter(|_| !self.read_only);
+
+        h_flex().id(self.id).gap_0p5().children((1..=MAX).map(|n| {
+            let fill = fill_fraction(value, n);
+            let mut star = div()
+                .relative()
+                .size(size)
+                .child(Icon::new(self.icon.clone()).size(size).text_color(empty))
+                .child(
+                    div()
+                        .absolute()
+                        .top_0()
+                        .left_0()
+                        .h_full()
+                        .w(size * fill)
+                        .overflow_hidden()
+                        .child(Icon::new(self.icon.clone()).size(size).text_color(color)),
+                );
+
+            if let Some(handler) = handler.clone() {
+                let targets: Vec<(f32, Pixels, Pixels)> = if self.half {
+                    vec![
+                        (n as f32 - 0.5, px(0.), size / 2.),
+                        (n as f32, size / 2., size / 2.),
+                    ]
+                } else {
+                    vec![(n as f32, px(0.), size)]
+                };
+                star = star.children(targets.into_iter().enumerate().map(
+                    |(ix, (target, left, width))| {
+                        let handler = handler.clone();
+                        div()
+                            .id(n * 2 + ix)
+                            .absolute()
+                            .top_0()
+                            .left(left)
+                            .w(width)
+                            .h_full()
+                            .cursor_pointer()
+                            .on_click(move |_, window, cx| {
+                                handler(&tapped_value(value, target), window, cx)
+                            })
+                    },
+                ));
+            }
+            star
+        }))
+    }
+}
+
+/// Formats a rating for display next to the stars, e.g. "3.7".
+pub fn format_rating(value: f32) -> SharedString {
+    format!("{:.1}", value).into()
+}
+
+/// Read-only stars for table cells.
+pub fn rating_cell(id: impl Into<ElementId>, value: f32) -> AnyElement {
+    StarRating::new(id)
+        .value(value)
+        .read_only(true)
+        .icon_size(px(14.))
+        .into_any_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fills(value: f32) -> Vec<f32> {
+        (1..=MAX).map(|n| fill_fraction(value, n)).collect()
+    }
+
+    #[test]
+    fn whole_values_fill_whole_icons() {
+        assert_eq!(fills(0.), vec![0., 0., 0., 0., 0.]);
+        assert_eq!(fills(3.), vec![1., 1., 1., 0., 0.]);
+        assert_eq!(fills(5.), vec![1., 1., 1., 1., 1.]);
+    }
+
+    #[test]
+    fn half_steps_and_averages_fill_part_of_an_icon() {
+        assert_eq!(fills(3.5), vec![1., 1., 1., 0.5, 0.]);
+        assert_eq!(fills(0.5), vec![0.5, 0., 0., 0., 0.]);
+        assert_eq!(fills(4.5), vec![1., 1., 1., 1., 0.5]);
+        let average = fills(3.75);
+        assert_eq!(average[3], 0.75);
+        assert_eq!(average[4], 0.);
+    }
+
+    #[test]
+    fn tapping_the_current_value_clears_it() {
+        assert_eq!(tapped_value(3., 3.), 0.);
+        assert_eq!(tapped_value(3.5, 3.5), 0.);
+        assert_eq!(tapped_value(3., 3.5), 3.5);
+        assert_eq!(tapped_value(3.5, 3.), 3.);
+        assert_eq!(tapped_value(0., 1.), 1.);
+    }
+}
@@ -0,0 +1,449 @@
+use std::time::Duration;
+
+use gpui::{
+    actions, ease_out_quint, prelude::*, px, Animation, AnimationExt as _, AnyElement, App,
+    Context, Div, Pixels, Point, SharedString, Window,
+};
+use gpui_component::WindowExt as _;
+
+use crate::i18n::t;
+use crate::sections::Section;
+use crate::ComponentGallery;
+
+actions!(gallery, [GoBack]);
+
+const TRANSITION: Duration = Duration::from_millis(240);
+/// How far a page slides in from the side during a transition.
+const TRANSITION_OFFSET: f32 = 48.;
+
+/// Standalone demo pages that aren't part of the scrolling gallery.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Demo {
+    Todo,
+}
+
+impl Demo {
+    pub const ALL: [Demo; 1] = [Demo::Todo];
+
+    fn slug(self) -> &'static str {
+        match self {
+            Demo::Todo => "todo",
+        }
+    }
+
+    fn title_key(self) -> &'static str {
+        match self {
+            Demo::Todo => "todo.title",
+        }
+    }
+}
+
+/// A page of the app: `/` is the whole gallery, `/section/<slug>` a single
+/// section, `/demo/<slug>` a standalone demo and `/settings` the settings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Route {
+    Home,
+    Section(Section),
+    Demo(Demo),
+    Settings,
+}
+
+impl Route {
+    /// The page for `section`. The header is the top of the gallery, so it maps to `/`.
+    pub fn section(section: Section) -> Route {
+        match section {
+            Section::Header => Route::Home,
+            section => Route::Section(section),
+        }
+    }
+
+    pub fn parse(path: &str) -> Option<Route> {
+        let segments = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>();
+        match segments.as_slice() {
+            [] => Some(Route::Home),
+            ["settings"] => Some(Route::Settings),
+            ["section", slug] => Section::ALL
+                .into_iter()
+                .find(|section| section.slug() == *slug)
+                .map(Route::section),
+            ["demo", slug] => Demo::ALL
+                .into_iter()
+                .find(|demo| demo.slug() == *slug)
+                .map(Route::Demo),
+            _ => None,
+        }
+    }
+
+    pub fn path(self) -> String {
+        match self {
+            Route::Home => "/".into(),
+            Route::Section(section) => format!("/section/{}", section.slug()),
+            Route::Demo(demo) => format!("/demo/{}", demo.slug()),
+            Route::Settings => "/settings".into(),
+        }
+    }
+
+    pub fn title(self, cx: &App) -> SharedString {
+        match self {
+            Route::Home => t(cx, "navigation.home"),
+            Route::Section(section) => t(cx, section.title_key()),
+            Route::Demo(demo) => t(cx, demo.title_key()),
+            Route::Settings => t(cx, "sidebar.settings"),
+        }
+    }
+
+    /// The routes from `/` down to this one, for the breadcrumb.
+    pub fn trail(self) -> Vec<Route> {
+        match self {
+            Route::Home => vec![Route::Home],
+            route => vec![Route::Home, route],
+        }
+    }
+
+    /// Whether the page shows `section`. The header is shown on every page.
+    pub fn shows(self, section: Section) -> bool {
+        match self {
+            Route::Home => true,
+            _ if section == Section::Header => true,
+            Route::Section(shown) => shown == section,
+            Route::Demo(_) | Route::Settings => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transition {
+    Push,
+    Pop,
+}
+
+struct Page {
+    route: Route,
+    /// Where the page was scrolled to when another was pushed over it.
+    scroll_offset: Point<Pixels>,
+}
+
+/// The navigation stack. It always holds at least the home page.
+pub struct Router {
+    stack: Vec<Page>,
+    transition: Option<Transition>,
+    /// Bumped on every navigation, so the page transition replays.
+    generation: usize,
+}
+
+impl Default for Router {
+    fn default() -> Self {
+        Self {
+            stack: vec![Page {
+                route: Route::Home,
+                scroll_offset: Point::default(),
+            }],
+            transition: None,
+            generation: 0,
+        }
+    }
+}
+
+impl Router {
+    pub fn current(&self) -> Route {
+        self.stack.last().map_or(Route::Home, |page| page.route)
+    }
+
+    pub fn can_pop(&self) -> bool {
+        self.stack.len() > 1
+    }
+
+    /// Goes to `route`: pops back to it if it is already on the stack,
+    /// otherwise pushes it. Returns whether the current route changed.
+    pub fn navigate(&mut self, route: Route, scroll_offset: Point<Pixels>) -> bool {
+        if route == self.current() {
+            return false;
+        }
+        if let Some(ix) = self.stack.iter().rposition(|page| page.route == route) {
+            self.stack.truncate(ix + 1);
+            self.start_transition(Transition::Pop);
+        } else {
+            if let Some(page) = self.stack.last_mut() {
+                page.scroll_offset = scroll_offset;
+            }
+            self.stack.push(Page {
+                route,
+                scroll_offset: Point::default(),
+            });
+            self.start_transition(Transition::Push);
+        }
+        true
+    }
+
+    /// Pops the current page. Returns `false`, leaving the stack alone, on the home page.
+    pub fn pop(&mut self) -> bool {
+        if !self.can_pop() {
+            return false;
+        }
+        self.stack.pop();
+        self.start_transition(Transition::Pop);
+        true
+    }
+
+    /// Scroll position to restore for the current page.
+    fn scroll_offset(&self) -> Point<Pixels> {
+        self.stack
+            .last()
+            .map_or(Point::default(), |page| page.scroll_offset)
+    }
+
+    fn start_transition(&mut self, transition: Transition) {
+        self.transition = Some(transition);
+        self.generation += 1;
+    }
+}
+
+impl ComponentGallery {
+    pub(crate) fn navigate(&mut self, route: Route, cx: &mut Context<Self>) {
+        if self.router.navigate(route, self.scroll_handle.offset()) {
+            self.scroll_handle.set_offset(self.router.scroll_offset());
+            cx.notify();
+        }
+    }
+
+    /// Goes to the route at `path`, e.g. `/section/forms`. Unknown paths are
+    /// logged and ignored.
+    pub(crate) fn navigate_to_path(&mut self, path: &str, cx: &mut Context<Self>) {
+        match Route::parse(path) {
+            Some(route) => self.navigate(route, cx),
+            None => log::error!("unknown route {}", path),
+        }
+    }
+
+    pub(crate) fn pop_route(&mut self, cx: &mut Context<Self>) -> bool {
+        if !self.router.pop() {
+            return false;
+        }
+        self.scroll_handle.set_offset(self.router.scroll_offset());
+        cx.notify();
+        true
+    }
+
+    /// Handles one back event: closes the topmost dialog or sheet if there is
+    /// one, otherwise pops the page stack. Returns `false` when there was
+    /// nothing to go back from, so the system can leave the app.
+    pub(crate) fn handle_back(&mut self, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        if window.has_active_dialog(cx) {
+            window.close_dialog(cx);
+            return true;
+        }
+        // A stacked sheet goes back to the one below it.
+        if self.sheet_depth(cx) > 0 {
+            self.pop_sheet(window, cx);
+            return true;
+        }
+        if window.has_active_sheet(cx) {
+            window.close_sheet(cx);
+            return true;
+        }
+        self.pop_route(cx)
+    }
+
+    /// The system back gesture. Dispatching [`GoBack`] to the window is all it
+    /// takes to simulate one, so navigation can be driven headless.
+    pub(crate) fn go_back(&mut self, _: &GoBack, window: &mut Window, cx: &mut Context<Self>) {
+        if !self.handle_back(window, cx) {
+            cx.propagate();
+        }
+    }
+
+    /// Slides the page in from the right after a push, or from the left after a pop.
+    pub(crate) fn animate_page(&self, page: Div) -> AnyElement {
+        let Some(transition) = self.router.transition else {
+            return page.into_any_element();
+        };
+        let offset = match transition {
+            Transition::Push => TRANSITION_OFFSET,
+            Transition::Pop => -TRANSITION_OFFSET,
+        };
+        page.relative()
+            .with_animation(
+                ("page", self.router.generation),
+                Animation::new(TRANSITION).with_easing(ease_out_quint()),
+                move |page, delta| {
+                    page.left(px(offset * (1. - delta)))
+                        .opacity(0.4 + 0.6 * delta)
+                },
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{div, point, Entity, TestAppContext, VisualTestContext};
+    use gpui_component::Placement;
+
+    use super::*;
+    use crate::test_support::open_gallery;
+
+    #[test]
+    fn parse_reads_every_route_back_from_its_path() {
+        let routes = [Route::Home, Route::Settings]
+            .into_iter()
+            .chain(Section::ALL.map(Route::section))
+            .chain(Demo::ALL.map(Route::Demo));
+        for route in routes {
+            assert_eq!(Route::parse(&route.path()), Some(route));
+        }
+    }
+
+    #[test]
+    fn parse_ignores_empty_segments() {
+        assert_eq!(Route::parse(""), Some(Route::Home));
+        assert_eq!(
+            Route::parse("//section//forms/"),
+            Some(Route::Section(Section::Forms))
+        );
+        assert_eq!(Route::parse("/section/top"), Some(Route::Home));
+    }
+
+    #[test]
+    fn parse_rejects_unknown_paths() {
+        for path in [
+            "/section",
+            "/section/nope",
+            "/section/forms/extra",
+            "/demo/nope",
+            "/settings/extra",
+            "/forms",
+        ] {
+            assert_eq!(Route::parse(path), None, "{path}");
+        }
+    }
+
+    #[test]
+    fn navigate_pushes_new_routes_and_ignores_the_current_one() {
+        let mut router = Router::default();
+        assert!(!router.navigate(Route::Home, Point::default()));
+        assert_eq!(router.generation, 0);
+
+        assert!(router.navigate(Route::Settings, Point::default()));
+        assert!(router.navigate(Route::Demo(Demo::Todo), Point::default()));
+        assert_eq!(router.current(), Route::Demo(Demo::Todo));
+        assert_eq!(router.stack.len(), 3);
+        assert_eq!(router.transition, Some(Transition::Push));
+        assert_eq!(router.generation, 2);
+    }
+
+    #[test]
+    fn navigate_to_a_route_on_the_stack_pops_back_to_it() {
+        let mut router = Router::default();
+        let offset = point(px(0.), px(-120.));
+        router.navigate(Route::Settings, offset);
+        router.navigate(Route::Section(Section::Forms), Point::default());
+
+        assert!(router.navigate(Route::Home, Point::default()));
+        assert_eq!(router.stack.len(), 1);
+        assert_eq!(router.transition, Some(Transition::Pop));
+        assert_eq!(router.scroll_offset(), offset);
+    }
+
+    #[test]
+    fn pop_restores_the_page_below_and_stops_at_home() {
+        let mut router = Router::default();
+        let offset = point(px(0.), px(-80.));
+        router.navigate(Route::Settings, offset);
+
+        assert!(router.pop());
+        assert_eq!(router.current(), Route::Home);
+        assert_eq!(router.scroll_offset(), offset);
+        assert_eq!(router.transition, Some(Transition::Pop));
+
+        assert!(!router.pop());
+        assert_eq!(router.current(), Route::Home);
+        assert_eq!(router.generation, 2);
+    }
+
+    /// Whether a dialog is open, how many gallery sheets are stacked, whether
+    /// a `Root` sheet is open, and the current route.
+    fn back_state(
+        gallery: &Entity<ComponentGallery>,
+        cx: &mut VisualTestContext,
+    ) -> (bool, usize, bool, Route) {
+        gallery.update_in(cx, |this, window, cx| {
+            (
+                window.has_active_dialog(cx),
+                this.sheet_depth(cx),
+                window.has_active_sheet(cx),
+                this.router.current(),
+            )
+        })
+    }
+
+    fn push_level(
+        this: &mut ComponentGallery,
+        title: &'static str,
+        window: &mut Window,
+        cx: &mut Context<ComponentGallery>,
+    ) {
+        this.push_sheet(
+            title,
+            Placement::Bottom,
+            |_, _| div().into_any_element(),
+            window,
+            cx,
+        );
+    }
+
+    #[gpui::test]
+    fn go_back_closes_overlays_from_the_top_before_popping_the_page(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        let forms = Route::Section(Section::Forms);
+        gallery.update_in(cx, |this, window, cx| {
+            this.navigate(forms, cx);
+            push_level(this, "first", window, cx);
+            push_level(this, "second", window, cx);
+            window.open_dialog(cx, |dialog, _, _| dialog);
+        });
+        cx.run_until_parked();
+        assert_eq!(back_state(&gallery, cx), (true, 2, true, forms));
+
+        cx.dispatch_action(GoBack);
+        assert_eq!(back_state(&gallery, cx), (false, 2, true, forms));
+
+        cx.dispatch_action(GoBack);
+        assert_eq!(back_state(&gallery, cx), (false, 1, true, forms));
+
+        cx.dispatch_action(GoBack);
+        assert_eq!(back_state(&gallery, cx), (false, 0, false, forms));
+
+        cx.dispatch_action(GoBack);
+        assert_eq!(back_state(&gallery, cx), (false, 0, false, Route::Home));
+    }
+
+    #[gpui::test]
+    fn a_sheet_opened_over_the_stack_replaces_it(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        gallery.update_in(cx, |this, window, cx| {
+            push_level(this, "first", window, cx);
+            push_level(this, "second", window, cx);
+            window.open_sheet(cx, |sheet, _, _| sheet);
+        });
+        cx.run_until_parked();
+        assert_eq!(back_state(&gallery, cx), (false, 0, true, Route::Home));
+
+        cx.dispatch_action(GoBack);
+        assert_eq!(back_state(&gallery, cx), (false, 0, false, Route::Home));
+
+        // The replaced stack doesn't come back with the next sheet.
+        gallery.update_in(cx, |this, window, cx| push_level(this, "again", window, cx));
+        assert_eq!(back_state(&gallery, cx), (false, 1, true, Route::Home));
+    }
+
+    #[gpui::test]
+    fn back_on_the_home_page_is_left_to_the_system(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        let handled = gallery.update_in(cx, |this, window, cx| this.handle_back(window, cx));
+        assert!(!handled);
+        assert_eq!(back_state(&gallery, cx), (false, 0, false, Route::Home));
+    }
+}
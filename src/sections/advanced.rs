@@ -1,37 +1,46 @@
+use std::ops::Range;
+
+use chrono::{Datelike as _, Duration, Local, NaiveDate};
 use gpui::{
-    div, px, AnyElement, App, Global, IntoElement, ParentElement, SharedString, Styled, Window,
+    div, prelude::FluentBuilder as _, px, AnyElement, App, Axis, Context, Entity, Global,
+    IntoElement, ParentElement, SharedString, Styled, Window,
 };
 use gpui_component::{
-    button::Button,
+    button::{Button, ButtonVariants},
+    calendar::{Date, Matcher},
     chart::{AreaChart, BarChart, LineChart, PieChart},
+    date_picker::{DatePicker, DateRangePreset},
     h_flex,
     list::{List, ListDelegate, ListItem, ListState},
-    menu::{ContextMenuExt, DropdownMenu as _, PopupMenuItem},
-    resizable::{h_resizable, resizable_panel},
+    menu::{DropdownMenu as _, PopupMenu, PopupMenuItem},
+    pagination::Pagination,
     scroll::{ScrollableElement as _, ScrollbarAxis},
     setting::{SettingField, SettingGroup, SettingItem, SettingPage, Settings},
-    sidebar::{
-        Sidebar, SidebarFooter, SidebarHeader, SidebarMenu, SidebarMenuItem, SidebarToggleButton,
-    },
+    switch::Switch,
     table::{Column, Table, TableDelegate, TableState},
     tree::{tree, TreeItem},
-    v_flex, v_virtual_list, ActiveTheme as _, IconName, Side,
+    v_flex, v_virtual_list, ActiveTheme as _, Theme, ThemeMode,
 };
 
+use crate::clipboard;
+use crate::focus::FocusTarget;
+use crate::history::Change;
+use crate::i18n::{format_date, t, t_args};
+use crate::keymap::{NextTab, ToggleSidebar, ToggleSidebarSide};
+use crate::loading::DataSet;
+use crate::panels::{PanelSpec, SplitGroup};
 use crate::ComponentGallery;
 
 #[derive(Clone)]
 pub struct SimpleListDelegate {
-    items: Vec<SharedString>,
+    items: Vec<usize>,
     selected: Option<usize>,
 }
 
 impl SimpleListDelegate {
     pub fn new() -> Self {
         Self {
-            items: (1..=12)
-                .map(|ix| SharedString::from(format!("List Item {}", ix)))
-                .collect(),
+            items: (1..=12).collect(),
             selected: None,
         }
     }
@@ -48,9 +57,9 @@ impl ListDelegate for SimpleListDelegate {
         &mut self,
         ix: gpui_component::IndexPath,
         _window: &mut Window,
-        _cx: &mut gpui::Context<ListState<Self>>,
+        cx: &mut gpui::Context<ListState<Self>>,
     ) -> Option<Self::Item> {
-        let label = self.items.get(ix.row)?.clone();
+        let label = t_args(cx, "list.item", &[("n", self.items.get(ix.row)?)]);
         Some(
             ListItem::new(ix)
                 .selected(self.selected == Some(ix.row))
@@ -70,14 +79,34 @@ impl ListDelegate for SimpleListDelegate {
 
 #[derive(Clone)]
 pub struct SimpleTableDelegate {
-    columns: Vec<Column>,
-    rows: Vec<[SharedString; 3]>,
+    columns: Vec<(&'static str, &'static str, gpui::Pixels)>,
+    rows: Vec<TableRow>,
+    /// Indices into `rows` that pass the date filter.
+    visible: Vec<usize>,
+    /// 1-based page of `visible` the table shows.
+    page: usize,
+    page_size: usize,
+}
+
+/// Rows-per-page choices for the demo table.
+pub const PAGE_SIZES: [usize; 3] = [10, 20, 50];
+
+/// How many rows the demo table's data source holds.
+const TABLE_ROWS: usize = 245;
+
+#[derive(Clone)]
+struct TableRow {
+    id: usize,
+    /// A message key, translated when rendered.
+    status: &'static str,
+    date: NaiveDate,
 }
 
 #[derive(Default)]
 pub struct DemoSettings {
     notifications: bool,
     username: SharedString,
+    compact_density: bool,
 }
 
 impl Global for DemoSettings {}
@@ -90,26 +119,300 @@ impl DemoSettings {
     pub fn global_mut(cx: &mut App) -> &mut DemoSettings {
         cx.global_mut::<DemoSettings>()
     }
+
+    /// Whether cards use tighter spacing.
+    pub fn compact_density(cx: &App) -> bool {
+        Self::global(cx).compact_density
+    }
+}
+
+/// Sidebars either side of an editor. Each edge panel collapses when dragged
+/// small enough.
+static WORKSPACE_LAYOUT: SplitGroup = SplitGroup {
+    id: "workspace-split",
+    axis: Axis::Horizontal,
+    panels: &[
+        PanelSpec::sized("resizable.left", 120., 64., 240.),
+        PanelSpec::flex("resizable.center"),
+        PanelSpec::sized("resizable.right", 120., 64., 240.),
+    ],
+};
+
+/// The editor and the bottom panel below it, nested in the workspace's center.
+static EDITOR_LAYOUT: SplitGroup = SplitGroup {
+    id: "editor-split",
+    axis: Axis::Vertical,
+    panels: &[
+        PanelSpec::flex("editor"),
+        PanelSpec::sized("resizable.bottom", 72., 40., 160.),
+    ],
+};
+
+impl ComponentGallery {
+    fn render_workspace(
+        &self,
+        is_compact: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let bottom = &EDITOR_LAYOUT.panels[1];
+        let bottom_collapsed = self.panels.is_collapsed(bottom);
+        let pane = |key: &'static str, cx: &mut Context<Self>| {
+            div()
+                .size_full()
+                .p_2()
+                .text_sm()
+                .child(t(cx, key))
+                .into_any_element()
+        };
+
+        let editor = self.render_split(
+            &EDITOR_LAYOUT,
+            vec![pane("resizable.center", cx), pane("resizable.bottom", cx)],
+            is_compact,
+            cx,
+        );
+        let workspace = self.render_split(
+            &WORKSPACE_LAYOUT,
+            vec![
+                pane("resizable.left", cx),
+                editor,
+                pane("resizable.right", cx),
+            ],
+            is_compact,
+            cx,
+        );
+
+        v_flex()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .flex_wrap()
+                    .child(
+                        self.focus_ring(
+                            FocusTarget::ToggleBottomPanel,
+                            Button::new("toggle-bottom-panel")
+                                .label(if bottom_collapsed {
+                                    t(cx, "resizable.show_bottom")
+                                } else {
+                                    t(cx, "resizable.hide_bottom")
+                                })
+                                .outline()
+                                .small()
+                                .on_click(
+                                    cx.listener(|this, _, _, cx| this.toggle_bottom_panel(cx)),
+                                ),
+                            window,
+                            cx,
+                        ),
+                    )
+                    .child(
+                        self.focus_ring(
+                            FocusTarget::ResetPanels,
+                            Button::new("reset-panels")
+                                .label(t(cx, "resizable.reset"))
+                                .ghost()
+                                .small()
+                                .on_click(cx.listener(|this, _, _, cx| {
+                                    this.panels.reset();
+                                    cx.notify();
+                                })),
+                            window,
+                            cx,
+                        ),
+                    ),
+            )
+            .child(
+                div()
+                    .h(px(220.))
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .child(workspace),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(t(cx, "resizable.hint")),
+            )
+    }
+
+    pub(crate) fn toggle_bottom_panel(&mut self, cx: &mut Context<Self>) {
+        self.panels.toggle_collapsed(&EDITOR_LAYOUT.panels[1]);
+        cx.notify();
+    }
+}
+
+/// Builds the demo dropdown. Checkable items read the same state as the rest of
+/// the UI, so toggling them here or elsewhere stays in sync.
+fn demo_menu(
+    menu: PopupMenu,
+    view: &Entity<ComponentGallery>,
+    window: &mut Window,
+    cx: &mut Context<PopupMenu>,
+) -> PopupMenu {
+    let is_dark = cx.theme().mode.is_dark();
+    let compact = DemoSettings::compact_density(cx);
+    let notifications = DemoSettings::global(cx).notifications;
+    let sidebar_collapsed = view.read(cx).sidebar_collapsed;
+
+    menu.item(
+        PopupMenuItem::new(t(cx, "menus.copy")).on_click(window.listener_for(
+            view,
+            |this, _, _, cx| {
+                // Copies the text input, like its Copy button.
+                clipboard::copy(this.input_state.read(cx).value(), cx);
+                this.menu_message = "menus.copied".into();
+                cx.notify();
+            },
+        )),
+    )
+    .item(
+        PopupMenuItem::new(t(cx, "menus.refresh")).on_click(window.listener_for(
+            view,
+            |this, _, _, cx| {
+                this.menu_message = "menus.refreshed".into();
+                cx.notify();
+            },
+        )),
+    )
+    .item(PopupMenuItem::new(t(cx, "menus.paste")).disabled(true))
+    .separator()
+    .menu_with_check(
+        t(cx, "keymap.toggle_sidebar"),
+        sidebar_collapsed,
+        Box::new(ToggleSidebar),
+    )
+    .menu(t(cx, "keymap.next_tab"), Box::new(NextTab))
+    .item(
+        PopupMenuItem::new(t(cx, "settings.notifications"))
+            .checked(notifications)
+            .on_click(|_, window, cx| {
+                let settings = DemoSettings::global_mut(cx);
+                settings.notifications = !settings.notifications;
+                window.refresh();
+            }),
+    )
+    .separator()
+    .submenu(t(cx, "menus.theme"), window, cx, move |menu, _, cx| {
+        [
+            (ThemeMode::Light, "menus.light"),
+            (ThemeMode::Dark, "menus.dark"),
+        ]
+        .into_iter()
+        .fold(menu, |menu, (mode, label)| {
+            menu.item(
+                PopupMenuItem::new(t(cx, label))
+                    .checked(is_dark == mode.is_dark())
+                    .on_click(move |_, window, cx| Theme::change(mode, Some(window), cx)),
+            )
+        })
+    })
+    .submenu(t(cx, "menus.density"), window, cx, move |menu, _, cx| {
+        [(false, "menus.comfortable"), (true, "menus.compact")]
+            .into_iter()
+            .fold(menu, |menu, (value, label)| {
+                menu.item(
+                    PopupMenuItem::new(t(cx, label))
+                        .checked(compact == value)
+                        .on_click(move |_, window, cx| {
+                            DemoSettings::global_mut(cx).compact_density = value;
+                            window.refresh();
+                        }),
+                )
+            })
+    })
 }
 
 impl SimpleTableDelegate {
-    pub fn new() -> Self {
+    /// Rows dated over the three months up to `today`.
+    pub fn new(today: NaiveDate) -> Self {
         let columns = vec![
-            Column::new("id", "ID").width(px(60.)),
-            Column::new("name", "Name").width(px(140.)),
-            Column::new("status", "Status").width(px(120.)),
+            ("id", "table.id", px(60.)),
+            ("name", "table.name", px(140.)),
+            ("status", "table.status", px(120.)),
+            ("date", "table.date", px(140.)),
         ];
-        let rows = (1..=8)
-            .map(|ix| {
-                [
-                    SharedString::from(ix.to_string()),
-                    SharedString::from(format!("Item {}", ix)),
-                    SharedString::from(if ix % 2 == 0 { "Active" } else { "Pending" }),
-                ]
+        // A few rows per day over the last three months, counting back from
+        // today, so the date presets always have something to show.
+        let (min, _) = date_bounds(today);
+        let days = (today - min).num_days() + 1;
+        let rows = (1..=TABLE_ROWS)
+            .map(|ix| TableRow {
+                id: ix,
+                status: if ix % 2 == 0 {
+                    "table.active"
+                } else {
+                    "table.pending"
+                },
+                date: today - Duration::days((ix as i64 - 1) % days),
             })
-            .collect();
+            .collect::<Vec<_>>();
+        let visible = (0..rows.len()).collect();
+
+        Self {
+            columns,
+            rows,
+            visible,
+            page: 1,
+            page_size: PAGE_SIZES[0],
+        }
+    }
+
+    /// Rows that pass the filter, across all pages.
+    pub fn total(&self) -> usize {
+        self.visible.len()
+    }
+
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.total().div_ceil(self.page_size).max(1)
+    }
+
+    /// The slice of `visible` on the current page.
+    pub fn page_range(&self) -> Range<usize> {
+        let start = ((self.page - 1) * self.page_size).min(self.total());
+        start..(start + self.page_size).min(self.total())
+    }
+
+    /// The 1-based first and last row on the page and the total, for the
+    /// "Showing 21–30 of 245" summary, or `None` if no row passes the filter.
+    pub fn summary(&self) -> Option<(usize, usize, usize)> {
+        let range = self.page_range();
+        (self.total() > 0).then(|| (range.start + 1, range.end, self.total()))
+    }
+
+    /// Moves to `page`, clamped to the pages that exist.
+    pub fn set_page(&mut self, page: usize) {
+        self.page = page.clamp(1, self.page_count());
+    }
+
+    pub fn set_page_size(&mut self, page_size: usize) {
+        self.page_size = page_size.max(1);
+        self.set_page(self.page);
+    }
 
-        Self { columns, rows }
+    /// Shows only rows dated within `range`, inclusive; `None` shows every row.
+    pub fn set_date_range(&mut self, range: Option<(NaiveDate, NaiveDate)>) {
+        self.visible = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| match range {
+                Some((start, end)) => (start..=end).contains(&row.date),
+                None => true,
+            })
+            .map(|(ix, _)| ix)
+            .collect();
+        self.set_page(self.page);
     }
 }
 
@@ -119,11 +422,12 @@ impl TableDelegate for SimpleTableDelegate {
     }
 
     fn rows_count(&self, _cx: &gpui::App) -> usize {
-        self.rows.len()
+        self.page_range().len()
     }
 
-    fn column(&self, col_ix: usize, _cx: &gpui::App) -> Column {
-        self.columns[col_ix].clone()
+    fn column(&self, col_ix: usize, cx: &gpui::App) -> Column {
+        let (key, label, width) = self.columns[col_ix];
+        Column::new(key, t(cx, label)).width(width)
     }
 
     fn render_td(
@@ -131,9 +435,154 @@ impl TableDelegate for SimpleTableDelegate {
         row_ix: usize,
         col_ix: usize,
         _window: &mut Window,
-        _cx: &mut gpui::Context<TableState<Self>>,
+        cx: &mut gpui::Context<TableState<Self>>,
     ) -> impl IntoElement {
-        self.rows[row_ix][col_ix].clone()
+        let row = &self.rows[self.visible[self.page_range().start + row_ix]];
+        match col_ix {
+            0 => SharedString::from(row.id.to_string()),
+            1 => t_args(cx, "table.item", &[("n", &row.id)]),
+            2 => t(cx, row.status),
+            _ => format_date(cx, row.date),
+        }
+    }
+}
+
+/// Earliest and latest dates the range picker accepts on `today`.
+fn date_bounds(today: NaiveDate) -> (NaiveDate, NaiveDate) {
+    (today - Duration::days(90), today)
+}
+
+fn is_outside_bounds(date: NaiveDate, today: NaiveDate) -> bool {
+    let (min, max) = date_bounds(today);
+    date < min || date > max
+}
+
+/// Dates the range picker won't select: anything outside [`date_bounds`] for
+/// the day the calendar is drawn, so it stays right across midnight.
+pub fn date_range_matcher() -> Matcher {
+    Matcher::custom(|date: &NaiveDate| is_outside_bounds(*date, Local::now().date_naive()))
+}
+
+/// Message key, start and end of each range preset on `today`.
+fn preset_ranges(today: NaiveDate) -> [(&'static str, NaiveDate, NaiveDate); 3] {
+    let month_start = today.with_day(1).unwrap_or(today);
+    [
+        ("table.today", today, today),
+        ("table.last_7_days", today - Duration::days(6), today),
+        ("table.this_month", month_start, today),
+    ]
+}
+
+fn date_presets(today: NaiveDate, cx: &App) -> Vec<DateRangePreset> {
+    preset_ranges(today)
+        .into_iter()
+        .map(|(label, start, end)| DateRangePreset::range(t(cx, label), start, end))
+        .collect()
+}
+
+impl ComponentGallery {
+    /// Applies the range picker's selection to the demo table. A half-picked
+    /// range (start without end) filters to that single day.
+    pub(crate) fn filter_table_by_date(&mut self, date: &Date, cx: &mut Context<Self>) {
+        let range = match *date {
+            Date::Range(Some(start), end) => Some((start, end.unwrap_or(start))),
+            _ => None,
+        };
+        self.table_date_range = range;
+        self.table_state.update(cx, |state, _| {
+            state.delegate_mut().set_date_range(range);
+        });
+        // A new filter changes what each page holds, so start over.
+        self.set_table_page(1, cx);
+    }
+
+    pub(crate) fn table_page_count(&self, cx: &App) -> usize {
+        self.table_state.read(cx).delegate().page_count()
+    }
+
+    /// Shows `page` of the table. History and the Pagination control both go
+    /// through here so the table and `pagination_page` never disagree.
+    pub(crate) fn set_table_page(&mut self, page: usize, cx: &mut Context<Self>) {
+        self.pagination_page = self.table_state.update(cx, |state, cx| {
+            state.delegate_mut().set_page(page);
+            state.refresh(cx);
+            state.delegate().page()
+        });
+        cx.notify();
+    }
+
+    fn set_table_page_size(&mut self, page_size: usize, cx: &mut Context<Self>) {
+        self.table_state.update(cx, |state, _| {
+            state.delegate_mut().set_page_size(page_size);
+        });
+        self.set_table_page(1, cx);
+    }
+
+    /// Page size buttons, the "Showing 21–30 of 245" summary and the pager.
+    fn render_table_pager(
+        &self,
+        is_compact: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let delegate = self.table_state.read(cx).delegate();
+        let (page_size, page_count) = (delegate.page_size(), delegate.page_count());
+        let summary = match delegate.summary() {
+            Some((start, end, total)) => t_args(
+                cx,
+                "table.showing",
+                &[("start", &start), ("end", &end), ("total", &total)],
+            ),
+            None => t(cx, "table.no_rows"),
+        };
+
+        v_flex()
+            .gap_2()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .flex_wrap()
+                    .items_center()
+                    .child(div().text_sm().child(t(cx, "table.rows_per_page")))
+                    .children(PAGE_SIZES.into_iter().map(|size| {
+                        Button::new(("page-size", size))
+                            .label(size.to_string())
+                            .small()
+                            .when(size == page_size, |this| this.primary())
+                            .when(size != page_size, |this| this.ghost())
+                            .on_click(
+                                cx.listener(move |this, _, _, cx| {
+                                    this.set_table_page_size(size, cx)
+                                }),
+                            )
+                    }))
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(summary),
+                    ),
+            )
+            .child(
+                self.focus_ring(
+                    FocusTarget::Pagination,
+                    Pagination::new("pagination")
+                        .current_page(self.pagination_page)
+                        .total_pages(page_count)
+                        .when(is_compact, |this| this.w_full())
+                        .on_click({
+                            let entity = cx.entity();
+                            move |page, window, cx| {
+                                entity.update(cx, |this, cx| {
+                                    this.apply(Change::Page(*page), window, cx)
+                                });
+                            }
+                        }),
+                    window,
+                    cx,
+                ),
+            )
     }
 }
 
@@ -144,35 +593,35 @@ struct DailyMetric {
     mobile: f64,
 }
 
-fn chart_data() -> Vec<DailyMetric> {
+fn chart_data(cx: &App) -> Vec<DailyMetric> {
     vec![
         DailyMetric {
-            day: "Mon".into(),
+            day: t(cx, "charts.mon"),
             desktop: 120.,
             mobile: 80.,
         },
         DailyMetric {
-            day: "Tue".into(),
+            day: t(cx, "charts.tue"),
             desktop: 160.,
             mobile: 95.,
         },
         DailyMetric {
-            day: "Wed".into(),
+            day: t(cx, "charts.wed"),
             desktop: 140.,
             mobile: 88.,
         },
         DailyMetric {
-            day: "Thu".into(),
+            day: t(cx, "charts.thu"),
             desktop: 190.,
             mobile: 120.,
         },
         DailyMetric {
-            day: "Fri".into(),
+            day: t(cx, "charts.fri"),
             desktop: 170.,
             mobile: 110.,
         },
         DailyMetric {
-            day: "Sat".into(),
+            day: t(cx, "charts.sat"),
             desktop: 130.,
             mobile: 70.,
         },
@@ -201,31 +650,21 @@ pub fn render(
         .gap_4()
         .child(
             view.card(
-                "Menus & Context",
+                "menus.title",
                 v_flex()
                     .gap_3()
                     .child(
-                        Button::new("menu")
-                            .label("Dropdown Menu")
-                            .outline()
-                            .dropdown_menu(move |menu, window, _cx| {
-                                menu.item(PopupMenuItem::new("Copy").on_click(window.listener_for(
-                                    &view_entity,
-                                    |this, _, _, cx| {
-                                        this.menu_message = "Copied".into();
-                                        cx.notify();
-                                    },
-                                )))
-                                .item(
-                                    PopupMenuItem::new("Refresh").on_click(window.listener_for(
-                                        &view_entity,
-                                        |this, _, _, cx| {
-                                            this.menu_message = "Refreshed".into();
-                                            cx.notify();
-                                        },
-                                    )),
-                                )
-                            }),
+                        view.focus_ring(
+                            FocusTarget::DropdownMenu,
+                            Button::new("menu")
+                                .label(t(cx, "menus.dropdown"))
+                                .outline()
+                                .dropdown_menu(move |menu, window, cx| {
+                                    demo_menu(menu, &view_entity, window, cx)
+                                }),
+                            window,
+                            cx,
+                        ),
                     )
                     .child(
                         div()
@@ -234,54 +673,146 @@ pub fn render(
                             .border_dashed()
                             .border_color(cx.theme().border)
                             .rounded_lg()
-                            .child("Right click for context menu")
-                            .context_menu(|this, _window, _cx| {
-                                this.link("Docs", "https://gpui.rs")
-                                    .separator()
-                                    .item(PopupMenuItem::new("Inspect"))
-                                    .item(PopupMenuItem::new("Disable"))
-                            }),
+                            .child(t(cx, "menus.context_hint")),
                     )
                     .child(
                         div()
                             .text_sm()
                             .text_color(cx.theme().muted_foreground)
-                            .child(format!("Menu: {}", view.menu_message)),
+                            .child(t_args(
+                                cx,
+                                "menus.status",
+                                &[("message", &t(cx, &view.menu_message))],
+                            )),
                     ),
                 cx,
             ),
         )
         .child(
             view.card(
-                "List",
-                List::new(&view.list_state)
-                    .p(px(8.))
-                    .border_1()
-                    .border_color(cx.theme().border)
-                    .rounded(cx.theme().radius),
+                "loading.title",
+                v_flex()
+                    .gap_3()
+                    .child(view.render_loading_controls(cx))
+                    .child(
+                        h_flex()
+                            .gap_3()
+                            .items_center()
+                            .child(
+                                Switch::new("simulate-errors")
+                                    .checked(view.loader.simulate_errors)
+                                    .label(t(cx, "loading.simulate_errors"))
+                                    .on_click(cx.listener(|this, checked, _, cx| {
+                                        this.loader.simulate_errors = *checked;
+                                        cx.notify();
+                                    })),
+                            )
+                            .child(
+                                view.focus_ring(
+                                    FocusTarget::ReloadData,
+                                    Button::new("reload-data")
+                                        .label(t(cx, "loading.reload"))
+                                        .outline()
+                                        .on_click(
+                                            cx.listener(|this, _, _, cx| this.reload_all_data(cx)),
+                                        ),
+                                    window,
+                                    cx,
+                                ),
+                            ),
+                    ),
                 cx,
             ),
         )
-        .child(view.card("Table", Table::new(&view.table_state).stripe(true), cx))
+        .child(view.card(
+            "list.title",
+            view.loadable(
+                DataSet::List,
+                |cx| {
+                    List::new(&view.list_state)
+                        .p(px(8.))
+                        .border_1()
+                        .border_color(cx.theme().border)
+                        .rounded(cx.theme().radius)
+                        .into_any_element()
+                },
+                cx,
+            ),
+            cx,
+        ))
         .child(
             view.card(
-                "Tree",
-                tree(&view.tree_state, |ix, entry, selected, _window, _cx| {
-                    ListItem::new(ix)
-                        .selected(selected)
-                        .pl(px(12.) * entry.depth() as f32)
-                        .child(entry.item().label.clone())
-                })
-                .border_1()
-                .border_color(cx.theme().border)
-                .rounded(cx.theme().radius)
-                .p_2(),
+                "table.title",
+                v_flex()
+                    .gap_3()
+                    .child(
+                        h_flex()
+                            .gap_3()
+                            .flex_wrap()
+                            .items_center()
+                            .child(
+                                DatePicker::new(&view.date_picker)
+                                    .placeholder(t(cx, "table.date_range"))
+                                    .presets(date_presets(Local::now().date_naive(), cx))
+                                    .cleanable(true),
+                            )
+                            .child(
+                                div()
+                                    .text_sm()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(match view.table_date_range {
+                                        Some((start, end)) if start == end => {
+                                            format_date(cx, start)
+                                        }
+                                        Some((start, end)) => t_args(
+                                            cx,
+                                            "table.range",
+                                            &[
+                                                ("start", &format_date(cx, start)),
+                                                ("end", &format_date(cx, end)),
+                                            ],
+                                        ),
+                                        None => t(cx, "table.all_dates"),
+                                    }),
+                            ),
+                    )
+                    .child(view.loadable(
+                        DataSet::Table,
+                        |_| {
+                            Table::new(&view.table_state)
+                                .stripe(true)
+                                .into_any_element()
+                        },
+                        cx,
+                    ))
+                    .child(view.render_table_pager(is_compact, window, cx)),
                 cx,
             ),
         )
+        .child(view.card(
+            "tree.title",
+            view.loadable(
+                DataSet::Tree,
+                |cx| {
+                    tree(&view.tree_state, |ix, entry, selected, _window, _cx| {
+                        ListItem::new(ix)
+                            .selected(selected)
+                            .pl(px(12.) * entry.depth() as f32)
+                            .child(entry.item().label.clone())
+                    })
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded(cx.theme().radius)
+                    .p_2()
+                    .into_any_element()
+                },
+                cx,
+            ),
+            cx,
+        ))
         .child(
             view.card(
-                "Virtual List",
+                "virtual_list.title",
                 div()
                     .border_1()
                     .border_color(cx.theme().border)
@@ -292,14 +823,14 @@ pub fn render(
                             cx.entity(),
                             "virtual-list",
                             view.virtual_sizes.clone(),
-                            move |story, visible_range: std::ops::Range<usize>, _, _| {
+                            move |story, visible_range: std::ops::Range<usize>, _, cx| {
                                 visible_range
                                     .map(|ix| {
-                                        div()
-                                            .h(px(32.))
-                                            .px_2()
-                                            .items_center()
-                                            .child(story.virtual_items[ix].clone())
+                                        div().h(px(32.)).px_2().items_center().child(t_args(
+                                            cx,
+                                            "virtual_list.row",
+                                            &[("n", &story.virtual_items[ix])],
+                                        ))
                                     })
                                     .collect()
                             },
@@ -310,235 +841,266 @@ pub fn render(
                 cx,
             ),
         )
+        .child(view.card(
+            "resizable.title",
+            view.render_workspace(is_compact, window, cx),
+            cx,
+        ))
         .child(
             view.card(
-                "Resizable",
-                div()
-                    .h(px(180.))
-                    .border_1()
-                    .border_color(cx.theme().border)
-                    .child(if is_compact {
-                        v_flex()
-                            .gap_2()
-                            .p_2()
-                            .child(div().child("Left"))
-                            .child(div().child("Center"))
-                            .child(div().child("Right"))
-                            .into_any_element()
-                    } else {
-                        h_resizable("resizable")
-                            .child(resizable_panel().size(px(120.)).child(div().child("Left")))
-                            .child(resizable_panel().child(div().child("Center")))
-                            .child(resizable_panel().size(px(120.)).child(div().child("Right")))
-                            .into_any_element()
-                    }),
-                cx,
-            ),
-        )
-        .child(view.card(
-            "Sidebar",
-            if is_compact {
+                "sidebar.title",
                 v_flex()
                     .gap_3()
                     .child(
-                        Sidebar::new("sidebar")
-                            .collapsed(view.sidebar_collapsed)
-                            .side(if view.sidebar_side_right {
-                                Side::Right
-                            } else {
-                                Side::Left
-                            })
-                            .header(SidebarHeader::new().child("Workspace").text_sm())
-                            .child(
-                                SidebarMenu::new()
-                                    .child(
-                                        SidebarMenuItem::new("Overview")
-                                            .icon(IconName::LayoutDashboard)
-                                            .active(true),
-                                    )
-                                    .child(
-                                        SidebarMenuItem::new("Components")
-                                            .icon(IconName::Frame)
-                                            .children([
-                                                SidebarMenuItem::new("Buttons"),
-                                                SidebarMenuItem::new("Forms"),
-                                            ]),
-                                    )
-                                    .child(
-                                        SidebarMenuItem::new("Settings")
-                                            .icon(IconName::Settings)
-                                            .disable(true),
-                                    ),
-                            )
-                            .footer(
-                                SidebarFooter::new().child("v0.1").child(
-                                    SidebarToggleButton::new()
-                                        .side(if view.sidebar_side_right {
-                                            Side::Right
-                                        } else {
-                                            Side::Left
-                                        })
-                                        .collapsed(view.sidebar_collapsed)
-                                        .on_click(cx.listener(|this, _, _, cx| {
-                                            this.sidebar_collapsed = !this.sidebar_collapsed;
-                                            cx.notify();
-                                        })),
-                                ),
-                            ),
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(t(
+                                cx,
+                                if is_compact {
+                                    "sidebar.drawer_hint"
+                                } else {
+                                    "sidebar.shell_hint"
+                                },
+                            )),
                     )
                     .child(
-                        v_flex()
+                        h_flex()
                             .gap_2()
+                            .flex_wrap()
                             .child(
-                                Button::new("toggle-side")
-                                    .label("Toggle Side")
-                                    .outline()
-                                    .on_click(cx.listener(|this, _, _, cx| {
-                                        this.sidebar_side_right = !this.sidebar_side_right;
-                                        cx.notify();
-                                    })),
-                            )
-                            .child(
-                                Button::new("toggle-collapse")
-                                    .label("Toggle Collapse")
-                                    .outline()
-                                    .on_click(cx.listener(|this, _, _, cx| {
-                                        this.sidebar_collapsed = !this.sidebar_collapsed;
-                                        cx.notify();
-                                    })),
-                            ),
-                    )
-                    .into_any_element()
-            } else {
-                h_flex()
-                    .gap_3()
-                    .child(
-                        Sidebar::new("sidebar")
-                            .collapsed(view.sidebar_collapsed)
-                            .side(if view.sidebar_side_right {
-                                Side::Right
-                            } else {
-                                Side::Left
-                            })
-                            .header(SidebarHeader::new().child("Workspace").text_sm())
-                            .child(
-                                SidebarMenu::new()
-                                    .child(
-                                        SidebarMenuItem::new("Overview")
-                                            .icon(IconName::LayoutDashboard)
-                                            .active(true),
-                                    )
-                                    .child(
-                                        SidebarMenuItem::new("Components")
-                                            .icon(IconName::Frame)
-                                            .children([
-                                                SidebarMenuItem::new("Buttons"),
-                                                SidebarMenuItem::new("Forms"),
-                                            ]),
-                                    )
-                                    .child(
-                                        SidebarMenuItem::new("Settings")
-                                            .icon(IconName::Settings)
-                                            .disable(true),
-                                    ),
-                            )
-                            .footer(
-                                SidebarFooter::new().child("v0.1").child(
-                                    SidebarToggleButton::new()
-                                        .side(if view.sidebar_side_right {
-                                            Side::Right
-                                        } else {
-                                            Side::Left
-                                        })
-                                        .collapsed(view.sidebar_collapsed)
-                                        .on_click(cx.listener(|this, _, _, cx| {
-                                            this.sidebar_collapsed = !this.sidebar_collapsed;
-                                            cx.notify();
+                                view.focus_ring(
+                                    FocusTarget::ToggleSide,
+                                    Button::new("toggle-side")
+                                        .label(t(cx, "sidebar.toggle_side"))
+                                        .outline()
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.toggle_sidebar_side(&ToggleSidebarSide, window, cx)
                                         })),
+                                    window,
+                                    cx,
                                 ),
-                            ),
-                    )
-                    .child(
-                        v_flex()
-                            .gap_2()
-                            .child(
-                                Button::new("toggle-side")
-                                    .label("Toggle Side")
-                                    .outline()
-                                    .on_click(cx.listener(|this, _, _, cx| {
-                                        this.sidebar_side_right = !this.sidebar_side_right;
-                                        cx.notify();
-                                    })),
                             )
                             .child(
-                                Button::new("toggle-collapse")
-                                    .label("Toggle Collapse")
-                                    .outline()
-                                    .on_click(cx.listener(|this, _, _, cx| {
-                                        this.sidebar_collapsed = !this.sidebar_collapsed;
-                                        cx.notify();
-                                    })),
+                                view.focus_ring(
+                                    FocusTarget::ToggleCollapse,
+                                    Button::new("toggle-collapse")
+                                        .label(t(cx, "sidebar.toggle_collapse"))
+                                        .outline()
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.toggle_sidebar(&ToggleSidebar, window, cx)
+                                        })),
+                                    window,
+                                    cx,
+                                ),
                             ),
-                    )
-                    .into_any_element()
-            },
-            cx,
-        ))
-        .child(view.card(
-            "Charts",
-            {
-                let data = chart_data();
-                v_flex()
-                    .gap_3()
-                    .child(
-                        AreaChart::new(data.clone())
-                            .x(|d| d.day.clone())
-                            .y(|d| d.desktop)
-                            .y(|d| d.mobile),
-                    )
-                    .child(
-                        BarChart::new(data.clone())
-                            .x(|d| d.day.clone())
-                            .y(|d| d.desktop),
-                    )
-                    .child(
-                        LineChart::new(data.clone())
-                            .x(|d| d.day.clone())
-                            .y(|d| d.mobile),
-                    )
-                    .child(PieChart::new(data.clone()).value(|d| d.desktop as f32))
-            },
-            cx,
-        ))
-        .child(view.card(
-            "Settings",
-            Settings::new("demo-settings").pages(vec![
-                SettingPage::new("General")
-                    .default_open(true)
-                    .group(
-                        SettingGroup::new()
-                            .title("Preferences")
-                            .items([
-                                SettingItem::new(
-                                    "Notifications",
-                                    SettingField::switch(
-                                        |cx| DemoSettings::global(cx).notifications,
-                                        |v, cx| DemoSettings::global_mut(cx).notifications = v,
-                                    ),
-                                )
-                                .description("Receive push notifications"),
-                                SettingItem::new(
-                                    "Username",
-                                    SettingField::input(
-                                        |cx| DemoSettings::global(cx).username.clone(),
-                                        |v, cx| DemoSettings::global_mut(cx).username = v,
-                                    ),
-                                )
-                                .description("Public display name"),
-                            ]),
                     ),
-            ]),
+                cx,
+            ),
+        )
+        .child(view.card(
+            "charts.title",
+            view.loadable(
+                DataSet::Charts,
+                |cx| {
+                    let data = chart_data(cx);
+                    v_flex()
+                        .gap_3()
+                        .child(
+                            AreaChart::new(data.clone())
+                                .x(|d| d.day.clone())
+                                .y(|d| d.desktop)
+                                .y(|d| d.mobile),
+                        )
+                        .child(
+                            BarChart::new(data.clone())
+                                .x(|d| d.day.clone())
+                                .y(|d| d.desktop),
+                        )
+                        .child(
+                            LineChart::new(data.clone())
+                                .x(|d| d.day.clone())
+                                .y(|d| d.mobile),
+                        )
+                        .child(PieChart::new(data.clone()).value(|d| d.desktop as f32))
+                        .into_any_element()
+                },
+                cx,
+            ),
             cx,
         ))
+        .child(render_settings(view, cx))
         .into_any_element()
 }
+
+/// The settings demo, also shown on its own at `/settings`.
+pub fn render_settings(
+    view: &ComponentGallery,
+    cx: &mut gpui::Context<ComponentGallery>,
+) -> AnyElement {
+    view.card(
+        "settings.title",
+        Settings::new("demo-settings").pages(vec![SettingPage::new(t(cx, "settings.general"))
+            .default_open(true)
+            .group(
+                SettingGroup::new()
+                    .title(t(cx, "settings.preferences"))
+                    .items([
+                        SettingItem::new(
+                            t(cx, "settings.notifications"),
+                            SettingField::switch(
+                                |cx| DemoSettings::global(cx).notifications,
+                                |v, cx| DemoSettings::global_mut(cx).notifications = v,
+                            ),
+                        )
+                        .description(t(cx, "settings.notifications_description")),
+                        SettingItem::new(
+                            t(cx, "settings.compact_density"),
+                            SettingField::switch(
+                                |cx| DemoSettings::global(cx).compact_density,
+                                |v, cx| DemoSettings::global_mut(cx).compact_density = v,
+                            ),
+                        )
+                        .description(t(cx, "settings.compact_density_description")),
+                        SettingItem::new(
+                            t(cx, "settings.username"),
+                            SettingField::input(
+                                |cx| DemoSettings::global(cx).username.clone(),
+                                |v, cx| DemoSettings::global_mut(cx).username = v,
+                            ),
+                        )
+                        .description(t(cx, "settings.username_description")),
+                    ]),
+            )]),
+        cx,
+    )
+    .into_any_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::*;
+    use crate::test_support::open_gallery;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn table() -> SimpleTableDelegate {
+        SimpleTableDelegate::new(date(2024, 3, 15))
+    }
+
+    #[test]
+    fn the_last_page_holds_the_remaining_rows() {
+        let mut table = table();
+        assert_eq!(table.page_count(), 25);
+        assert_eq!(table.page_range(), 0..10);
+
+        table.set_page(25);
+        assert_eq!(table.page_range(), 240..245);
+        table.set_page(99);
+        assert_eq!(table.page(), 25);
+        table.set_page(0);
+        assert_eq!(table.page(), 1);
+    }
+
+    #[test]
+    fn a_bigger_page_size_keeps_the_page_in_range() {
+        let mut table = table();
+        table.set_page(20);
+        table.set_page_size(50);
+        assert_eq!(table.page(), 5);
+        assert_eq!(table.page_range(), 200..245);
+
+        table.set_page_size(0);
+        assert_eq!(table.page_size(), 1);
+    }
+
+    #[test]
+    fn the_summary_counts_rows_from_one() {
+        let mut table = table();
+        table.set_page(3);
+        assert_eq!(table.summary(), Some((21, 30, 245)));
+        table.set_page(25);
+        assert_eq!(table.summary(), Some((241, 245, 245)));
+    }
+
+    #[test]
+    fn the_date_filter_keeps_rows_in_the_range() {
+        let mut table = table();
+        let today = date(2024, 3, 15);
+        // Rows cycle through the last 91 days, so each day has two or three.
+        table.set_date_range(Some((today - Duration::days(6), today)));
+        assert_eq!(table.total(), 21);
+        assert_eq!(table.page_count(), 3);
+
+        table.set_date_range(Some((today + Duration::days(1), today + Duration::days(2))));
+        assert_eq!(table.total(), 0);
+        assert_eq!(table.page(), 1);
+        assert_eq!(table.page_range(), 0..0);
+        assert_eq!(table.summary(), None);
+
+        table.set_date_range(None);
+        assert_eq!(table.total(), TABLE_ROWS);
+    }
+
+    #[gpui::test]
+    fn changing_the_date_filter_goes_back_to_the_first_page(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        gallery.update(cx, |this, cx| {
+            this.set_table_page(3, cx);
+            assert_eq!(this.pagination_page, 3);
+
+            let today = this.table_state.read(cx).delegate().rows[0].date;
+            let start = today - Duration::days(30);
+            this.filter_table_by_date(&Date::Range(Some(start), Some(today)), cx);
+            assert_eq!(this.pagination_page, 1);
+            assert_eq!(this.table_state.read(cx).delegate().page(), 1);
+            assert_eq!(this.table_date_range, Some((start, today)));
+        });
+    }
+
+    #[test]
+    fn rows_count_back_from_today_within_the_bounds() {
+        let table = table();
+        let (min, max) = date_bounds(date(2024, 3, 15));
+        assert_eq!((min, max), (date(2023, 12, 16), date(2024, 3, 15)));
+        assert_eq!(table.rows[0].date, max);
+        assert!(table.rows.iter().all(|row| (min..=max).contains(&row.date)));
+        assert!(table.rows.iter().any(|row| row.date == min));
+    }
+
+    #[test]
+    fn the_picker_accepts_only_dates_within_the_bounds() {
+        let today = date(2024, 3, 15);
+        assert!(!is_outside_bounds(today, today));
+        assert!(!is_outside_bounds(date(2023, 12, 16), today));
+        assert!(is_outside_bounds(date(2023, 12, 15), today));
+        assert!(is_outside_bounds(date(2024, 3, 16), today));
+        // The next day, the bounds move with it.
+        assert!(!is_outside_bounds(date(2024, 3, 16), date(2024, 3, 16)));
+    }
+
+    #[test]
+    fn presets_end_today() {
+        assert_eq!(
+            preset_ranges(date(2024, 3, 15)),
+            [
+                ("table.today", date(2024, 3, 15), date(2024, 3, 15)),
+                ("table.last_7_days", date(2024, 3, 9), date(2024, 3, 15)),
+                ("table.this_month", date(2024, 3, 1), date(2024, 3, 15)),
+            ]
+        );
+        // Across a year boundary, and on the first of the month.
+        assert_eq!(
+            preset_ranges(date(2024, 1, 1)),
+            [
+                ("table.today", date(2024, 1, 1), date(2024, 1, 1)),
+                ("table.last_7_days", date(2023, 12, 26), date(2024, 1, 1)),
+                ("table.this_month", date(2024, 1, 1), date(2024, 1, 1)),
+            ]
+        );
+    }
+}
@@ -1,157 +1,202 @@
 use gpui::prelude::FluentBuilder as _;
-use gpui::{px, AnyElement, IntoElement, ParentElement, Styled, Window};
+use gpui::{div, px, AnyElement, App, Context, IntoElement, ParentElement, Styled, Window};
 use gpui_component::{
     button::{Button, ButtonGroup, ButtonVariant, ButtonVariants},
     h_flex,
-    notification::Notification,
+    notification::NotificationType,
     v_flex, WindowExt as _,
 };
 
+use crate::dialogs;
+use crate::focus::FocusTarget;
+use crate::i18n::{t, t_args};
+use crate::notifications;
 use crate::ComponentGallery;
 
+pub fn notify(window: &mut Window, cx: &mut App) {
+    window.defer(cx, |window, cx| {
+        let message = t(cx, "buttons.notification");
+        notifications::push(NotificationType::Info, message, window, cx);
+    });
+}
+
+pub fn open_dialog(window: &mut Window, cx: &mut App) {
+    let answer = dialogs::confirm(
+        t(cx, "buttons.dialog_title"),
+        t(cx, "buttons.dialog_body"),
+        window,
+        cx,
+    );
+    let handle = window.window_handle();
+    cx.spawn(async move |cx| {
+        let key = match answer.await {
+            Some(()) => "buttons.dialog_confirmed",
+            None => "buttons.dialog_cancelled",
+        };
+        handle
+            .update(cx, |_, window, cx| {
+                let message = t(cx, key);
+                notifications::push(NotificationType::Info, message, window, cx);
+            })
+            .ok();
+    })
+    .detach();
+}
+
+impl ComponentGallery {
+    /// Prompts for a name and greets the user with it.
+    pub(crate) fn ask_name(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let default = self.greeting_name.clone().unwrap_or_default();
+        let answer = dialogs::prompt(t(cx, "buttons.prompt_title"), default, window, cx);
+        cx.spawn(async move |this, cx| {
+            let Some(name) = answer.await else {
+                return;
+            };
+            this.update(cx, |this, cx| {
+                let name = name.trim();
+                this.greeting_name = (!name.is_empty()).then(|| name.to_string().into());
+                cx.notify();
+            })
+            .ok();
+        })
+        .detach();
+    }
+}
+
+pub fn open_sheet(window: &mut Window, cx: &mut App) {
+    window.defer(cx, |window, cx| {
+        window.open_sheet(cx, move |sheet, _, cx| {
+            sheet
+                .title(t(cx, "buttons.sheet_title"))
+                .child(t(cx, "buttons.sheet_body"))
+        });
+    });
+}
+
 pub fn render(
     view: &mut ComponentGallery,
     window: &mut Window,
     cx: &mut gpui::Context<ComponentGallery>,
 ) -> AnyElement {
     let is_compact = window.bounds().size.width <= px(680.);
+    let variants = [
+        (
+            FocusTarget::Primary,
+            Button::new("primary")
+                .label(t(cx, "buttons.primary"))
+                .primary(),
+        ),
+        (
+            FocusTarget::Secondary,
+            Button::new("secondary")
+                .label(t(cx, "buttons.secondary"))
+                .with_variant(ButtonVariant::Secondary),
+        ),
+        (
+            FocusTarget::Outline,
+            Button::new("outline")
+                .label(t(cx, "buttons.outline"))
+                .outline(),
+        ),
+        (
+            FocusTarget::Ghost,
+            Button::new("ghost").label(t(cx, "buttons.ghost")).ghost(),
+        ),
+    ];
+    let actions = [
+        (
+            FocusTarget::Notify,
+            Button::new("notify")
+                .label(t(cx, "buttons.notify"))
+                .on_click(|_, window, cx| notify(window, cx)),
+        ),
+        (
+            FocusTarget::OpenDialog,
+            Button::new("open-dialog")
+                .label(t(cx, "buttons.open_dialog"))
+                .primary()
+                .on_click(|_, window, cx| open_dialog(window, cx)),
+        ),
+        (
+            FocusTarget::OpenSheet,
+            Button::new("open-sheet")
+                .label(t(cx, "buttons.open_sheet"))
+                .outline()
+                .on_click(|_, window, cx| open_sheet(window, cx)),
+        ),
+        (
+            FocusTarget::ConfirmReset,
+            Button::new("confirm-reset")
+                .label(t(cx, "buttons.reset"))
+                .danger()
+                .on_click(cx.listener(|this, _, window, cx| this.confirm_reset(window, cx))),
+        ),
+        (
+            FocusTarget::PromptName,
+            Button::new("prompt-name")
+                .label(t(cx, "buttons.ask_name"))
+                .outline()
+                .on_click(cx.listener(|this, _, window, cx| this.ask_name(window, cx))),
+        ),
+    ];
+
+    let row = |gap| {
+        if is_compact {
+            v_flex().gap_2()
+        } else {
+            h_flex().gap(gap)
+        }
+    };
+
     view.card(
-        "Buttons & Actions",
+        "buttons.title",
         v_flex()
             .gap_3()
-            .child(if is_compact {
-                v_flex()
-                    .gap_2()
-                    .child(Button::new("primary").label("Primary").primary().w_full())
-                    .child(
-                        Button::new("secondary")
-                            .label("Secondary")
-                            .with_variant(ButtonVariant::Secondary)
-                            .w_full(),
-                    )
-                    .child(Button::new("outline").label("Outline").outline().w_full())
-                    .child(Button::new("ghost").label("Ghost").ghost().w_full())
-                    .into_any_element()
-            } else {
-                h_flex()
-                    .gap_2()
-                    .child(Button::new("primary").label("Primary").primary())
-                    .child(
-                        Button::new("secondary")
-                            .label("Secondary")
-                            .with_variant(ButtonVariant::Secondary),
-                    )
-                    .child(Button::new("outline").label("Outline").outline())
-                    .child(Button::new("ghost").label("Ghost").ghost())
-                    .into_any_element()
-            })
             .child(
-                ButtonGroup::new("actions")
-                    .outline()
-                    .compact()
-                    .when(is_compact, |this| this.w_full())
-                    .child(Button::new("left").label("Left"))
-                    .child(Button::new("center").label("Center"))
-                    .child(Button::new("right").label("Right")),
+                row(px(8.)).children(variants.into_iter().map(|(target, button)| {
+                    view.focus_ring(target, button.when(is_compact, |b| b.w_full()), window, cx)
+                        .when(is_compact, |this| this.w_full())
+                })),
+            )
+            .child(
+                view.focus_ring(
+                    FocusTarget::ButtonGroup,
+                    ButtonGroup::new("actions")
+                        .outline()
+                        .compact()
+                        .when(is_compact, |this| this.w_full())
+                        .child(
+                            Button::new("left")
+                                .label(t(cx, "buttons.left"))
+                                .selected(view.button_group_index == 0),
+                        )
+                        .child(
+                            Button::new("center")
+                                .label(t(cx, "buttons.center"))
+                                .selected(view.button_group_index == 1),
+                        )
+                        .child(
+                            Button::new("right")
+                                .label(t(cx, "buttons.right"))
+                                .selected(view.button_group_index == 2),
+                        ),
+                    window,
+                    cx,
+                )
+                .when(is_compact, |this| this.w_full()),
+            )
+            .child(
+                row(px(12.)).children(actions.into_iter().map(|(target, button)| {
+                    view.focus_ring(target, button.when(is_compact, |b| b.w_full()), window, cx)
+                        .when(is_compact, |this| this.w_full())
+                })),
             )
-            .child(if is_compact {
-                v_flex()
-                    .gap_2()
-                    .child(Button::new("notify").label("Notify").w_full().on_click(
-                        |_, window, cx| {
-                            window.defer(cx, |window, cx| {
-                                window.push_notification(
-                                    Notification::new()
-                                        .message("Notification triggered")
-                                        .autohide(true),
-                                    cx,
-                                );
-                            });
-                        },
-                    ))
-                    .child(
-                        Button::new("open-dialog")
-                            .label("Open Dialog")
-                            .primary()
-                            .w_full()
-                            .on_click(|_, window: &mut Window, cx| {
-                                window.defer(cx, |window, cx| {
-                                    window.open_dialog(cx, move |dialog, _, _| {
-                                        dialog.title("Example Dialog").child(
-                                            v_flex()
-                                                .gap_2()
-                                                .child("This is a dialog in GPUI Component.")
-                                                .child(Button::new("ok").label("OK").primary()),
-                                        )
-                                    });
-                                });
-                            }),
-                    )
-                    .child(
-                        Button::new("open-sheet")
-                            .label("Open Sheet")
-                            .outline()
-                            .w_full()
-                            .on_click(|_, window: &mut Window, cx| {
-                                window.defer(cx, |window, cx| {
-                                    window.open_sheet(cx, move |sheet, _, _| {
-                                        sheet
-                                            .title("Example Sheet")
-                                            .child("This is a sheet drawer.")
-                                    });
-                                });
-                            }),
-                    )
-                    .into_any_element()
-            } else {
-                h_flex()
-                    .gap_3()
-                    .child(
-                        Button::new("notify")
-                            .label("Notify")
-                            .on_click(|_, window, cx| {
-                                window.defer(cx, |window, cx| {
-                                    window.push_notification(
-                                        Notification::new()
-                                            .message("Notification triggered")
-                                            .autohide(true),
-                                        cx,
-                                    );
-                                });
-                            }),
-                    )
-                    .child(
-                        Button::new("open-dialog")
-                            .label("Open Dialog")
-                            .primary()
-                            .on_click(|_, window: &mut Window, cx| {
-                                window.defer(cx, |window, cx| {
-                                    window.open_dialog(cx, move |dialog, _, _| {
-                                        dialog.title("Example Dialog").child(
-                                            v_flex()
-                                                .gap_2()
-                                                .child("This is a dialog in GPUI Component.")
-                                                .child(Button::new("ok").label("OK").primary()),
-                                        )
-                                    });
-                                });
-                            }),
-                    )
-                    .child(
-                        Button::new("open-sheet")
-                            .label("Open Sheet")
-                            .outline()
-                            .on_click(|_, window: &mut Window, cx| {
-                                window.defer(cx, |window, cx| {
-                                    window.open_sheet(cx, move |sheet, _, _| {
-                                        sheet
-                                            .title("Example Sheet")
-                                            .child("This is a sheet drawer.")
-                                    });
-                                });
-                            }),
-                    )
-                    .into_any_element()
+            .when_some(view.greeting_name.clone(), |this, name| {
+                this.child(div().text_sm().child(t_args(
+                    cx,
+                    "buttons.greeting",
+                    &[("name", &name)],
+                )))
             }),
         cx,
     )
@@ -1,52 +1,531 @@
-use gpui::{div, AnyElement, IntoElement, ParentElement, Styled, Window};
+use gpui::{
+    div, px, AnyElement, App, Context, Focusable, IntoElement, ParentElement, Styled, Window,
+};
 use gpui_component::{
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::{Input, InputEvent},
     rating::Rating,
     slider::Slider,
     stepper::{Stepper, StepperItem},
-    v_flex,
+    table::{Column, Table, TableDelegate, TableState},
+    v_flex, ActiveTheme as _, IconName, Sizable, StyledExt as _,
 };
 
+use crate::focus::FocusTarget;
+use crate::history::Change;
+use crate::i18n::{t, t_args};
+use crate::rating::{format_rating, rating_cell, StarRating};
+use crate::wizard::{STEP_DONE, STEP_LOGIN, STEP_VERIFY};
 use crate::ComponentGallery;
 
-pub fn render(
-    view: &mut ComponentGallery,
-    _window: &mut Window,
-    cx: &mut gpui::Context<ComponentGallery>,
-) -> AnyElement {
-    let slider_value = view.slider_state.read(cx).value().start();
+/// Bounds and step of the volume slider, shared with its numeric input.
+pub const VOLUME_MIN: f32 = 0.;
+pub const VOLUME_MAX: f32 = 100.;
+pub const VOLUME_STEP: f32 = 1.;
+
+/// Clamps `value` to the volume bounds and rounds it to the nearest step.
+fn snap_volume(value: f32) -> f32 {
+    let snapped = VOLUME_MIN + ((value - VOLUME_MIN) / VOLUME_STEP).round() * VOLUME_STEP;
+    snapped.clamp(VOLUME_MIN, VOLUME_MAX)
+}
 
+pub fn format_volume(value: f32) -> String {
+    format!("{}", value as i32)
+}
+
+impl ComponentGallery {
+    /// Moves the volume slider, keeping the derived progress value in step.
+    pub(crate) fn set_slider_value(
+        &mut self,
+        value: f32,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.slider_value = value;
+        self.progress_value = (value / 100.0).clamp(0.0, 1.0) * 100.0;
+        self.slider_state
+            .update(cx, |state, cx| state.set_value(value, window, cx));
+        self.sync_slider_input(window, cx);
+        cx.notify();
+    }
+
+    /// Writes the slider value into the numeric input, unless the user is
+    /// typing there; the input is normalized when it loses focus instead.
+    pub(crate) fn sync_slider_input(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        if self.slider_input.focus_handle(cx).is_focused(window) {
+            return;
+        }
+        let text = format_volume(self.slider_value);
+        if self.slider_input.read(cx).value() != text {
+            self.slider_input
+                .update(cx, |state, cx| state.set_value(text, window, cx));
+        }
+        self.slider_input_error = None;
+    }
+
+    /// Moves the slider as the user types. Numbers are clamped and snapped to
+    /// the step; anything else shows an inline error until the input is left,
+    /// at which point it reverts to the slider's value.
+    pub(crate) fn on_slider_input(
+        &mut self,
+        ev: &InputEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match ev {
+            InputEvent::Change => {
+                let text = self.slider_input.read(cx).value();
+                match text.trim().parse::<f32>() {
+                    Ok(value) if value.is_finite() => {
+                        self.slider_input_error = None;
+                        let value = snap_volume(value);
+                        if value != self.slider_value {
+                            self.apply(Change::Slider(value), window, cx);
+                        }
+                    }
+                    _ if text.trim().is_empty() => {
+                        self.slider_input_error = Some("controls.value_required")
+                    }
+                    _ => self.slider_input_error = Some("controls.not_a_number"),
+                }
+            }
+            InputEvent::Blur | InputEvent::PressEnter { .. } => {
+                let text = format_volume(self.slider_value);
+                self.slider_input
+                    .update(cx, |state, cx| state.set_value(text, window, cx));
+                self.slider_input_error = None;
+            }
+            _ => return,
+        }
+        cx.notify();
+    }
+}
+
+/// Sample reviews: reviewer, stars and a comment message key.
+const REVIEWS: [(&str, f32, &str); 7] = [
+    ("Ada", 5.0, "rating.review_1"),
+    ("Ben", 4.0, "rating.review_2"),
+    ("Chen", 3.5, "rating.review_3"),
+    ("Dana", 2.0, "rating.review_4"),
+    ("Eli", 4.5, "rating.review_5"),
+    ("Fay", 3.0, "rating.review_6"),
+    ("Gus", 4.0, "rating.review_7"),
+];
+
+fn average_rating() -> f32 {
+    REVIEWS.iter().map(|(_, stars, _)| stars).sum::<f32>() / REVIEWS.len() as f32
+}
+
+/// The review list, with read-only stars in the Rating column.
+pub struct ReviewTableDelegate {
+    columns: Vec<(&'static str, &'static str, gpui::Pixels)>,
+}
+
+impl ReviewTableDelegate {
+    pub fn new() -> Self {
+        Self {
+            columns: vec![
+                ("reviewer", "rating.reviewer", px(100.)),
+                ("stars", "rating.stars", px(110.)),
+                ("comment", "rating.comment", px(260.)),
+            ],
+        }
+    }
+}
+
+impl TableDelegate for ReviewTableDelegate {
+    fn columns_count(&self, _cx: &App) -> usize {
+        self.columns.len()
+    }
+
+    fn rows_count(&self, _cx: &App) -> usize {
+        REVIEWS.len()
+    }
+
+    fn column(&self, col_ix: usize, cx: &App) -> Column {
+        let (key, label, width) = self.columns[col_ix];
+        Column::new(key, t(cx, label)).width(width)
+    }
+
+    fn render_td(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        _window: &mut Window,
+        cx: &mut Context<TableState<Self>>,
+    ) -> impl IntoElement {
+        let (reviewer, stars, comment) = REVIEWS[row_ix];
+        match col_ix {
+            0 => reviewer.into_any_element(),
+            1 => rating_cell(("review-stars", row_ix), stars),
+            _ => t(cx, comment).into_any_element(),
+        }
+    }
+}
+
+/// Read-only average, a custom-icon rating and the review table.
+fn render_ratings(view: &mut ComponentGallery, cx: &mut Context<ComponentGallery>) -> AnyElement {
+    let average = average_rating();
     view.card(
-        "Sliders & Rating",
+        "rating.title",
         v_flex()
             .gap_3()
             .child(
-                v_flex()
-                    .gap_2()
-                    .child(div().child("Volume"))
-                    .child(Slider::new(&view.slider_state))
-                    .child(div().text_sm().child(format!("{}%", slider_value as i32)))
+                h_flex()
+                    .gap_3()
+                    .items_center()
                     .child(
-                        Rating::new("rating")
-                            .value(view.rating_value)
-                            .max(5)
-                            .on_click(cx.listener(|this, value, _, cx| {
-                                this.rating_value = *value;
+                        StarRating::new("average-rating")
+                            .value(average)
+                            .read_only(true),
+                    )
+                    .child(div().text_sm().child(t_args(
+                        cx,
+                        "rating.average",
+                        &[
+                            ("value", &format_rating(average)),
+                            ("count", &REVIEWS.len()),
+                        ],
+                    ))),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .items_center()
+                    .child(
+                        StarRating::new("heart-rating")
+                            .value(view.heart_rating)
+                            .icon(IconName::Heart)
+                            .color(cx.theme().danger)
+                            .on_change(cx.listener(|this, value, _, cx| {
+                                this.heart_rating = *value;
                                 cx.notify();
                             })),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(t(cx, "rating.clear_hint")),
                     ),
             )
             .child(
-                Stepper::new("stepper")
-                    .selected_index(view.stepper_step)
-                    .on_click(cx.listener(|this, step, _, cx| {
-                        this.stepper_step = *step;
-                        cx.notify();
-                    }))
-                    .item(StepperItem::new().child("Login"))
-                    .item(StepperItem::new().child("Verify"))
-                    .item(StepperItem::new().child("Done")),
+                div()
+                    .h(px(280.))
+                    .child(Table::new(&view.review_table).stripe(true)),
             ),
         cx,
     )
     .into_any_element()
 }
+
+fn field_label(key: &'static str, cx: &App) -> impl IntoElement {
+    div().text_sm().child(t(cx, key))
+}
+
+/// The form for the Stepper's current step, with Back and Next below it.
+fn render_wizard(
+    view: &mut ComponentGallery,
+    window: &mut Window,
+    cx: &mut Context<ComponentGallery>,
+) -> impl IntoElement {
+    let step = view.stepper_step;
+    let body = match step {
+        STEP_LOGIN => v_flex()
+            .gap_2()
+            .child(field_label("wizard.username", cx))
+            .child(Input::new(&view.wizard.username))
+            .child(field_label("wizard.password", cx))
+            .child(Input::new(&view.wizard.password).mask_toggle()),
+        STEP_VERIFY => v_flex()
+            .gap_2()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(t(cx, "wizard.code_hint")),
+            )
+            .child(field_label("wizard.code", cx))
+            .child(Input::new(&view.wizard.code)),
+        _ => {
+            let username = view.wizard.username.read(cx).value();
+            let password = view.wizard.password.read(cx).value();
+            let code = view.wizard.code.read(cx).value();
+            v_flex()
+                .gap_1()
+                .text_sm()
+                .child(div().font_semibold().child(t(cx, "wizard.summary")))
+                .child(t_args(
+                    cx,
+                    "wizard.summary_username",
+                    &[("name", &username)],
+                ))
+                .child(t_args(
+                    cx,
+                    "wizard.summary_password",
+                    &[("mask", &"•".repeat(password.chars().count()))],
+                ))
+                .child(t_args(cx, "wizard.summary_code", &[("code", &code)]))
+        }
+    };
+
+    v_flex()
+        .gap_3()
+        .p_3()
+        .border_1()
+        .border_color(cx.theme().border)
+        .rounded(cx.theme().radius)
+        .child(body)
+        .children(view.wizard.error.map(|key| {
+            div()
+                .text_sm()
+                .text_color(cx.theme().danger)
+                .child(t(cx, key))
+        }))
+        .child(
+            h_flex()
+                .gap_2()
+                .justify_end()
+                .child(
+                    view.focus_ring(
+                        FocusTarget::WizardBack,
+                        Button::new("wizard-back")
+                            .label(t(cx, "wizard.back"))
+                            .ghost()
+                            .disabled(step == STEP_LOGIN)
+                            .on_click(
+                                cx.listener(|this, _, window, cx| this.wizard_back(window, cx)),
+                            ),
+                        window,
+                        cx,
+                    ),
+                )
+                .child(
+                    view.focus_ring(
+                        FocusTarget::WizardNext,
+                        Button::new("wizard-next")
+                            .label(t(
+                                cx,
+                                if step == STEP_DONE {
+                                    "wizard.start_over"
+                                } else {
+                                    "wizard.next"
+                                },
+                            ))
+                            .primary()
+                            .on_click(
+                                cx.listener(|this, _, window, cx| this.wizard_next(window, cx)),
+                            ),
+                        window,
+                        cx,
+                    ),
+                ),
+        )
+}
+
+pub fn render(
+    view: &mut ComponentGallery,
+    window: &mut Window,
+    cx: &mut gpui::Context<ComponentGallery>,
+) -> AnyElement {
+    let slider_value = view.slider_state.read(cx).value().start();
+    let price = view.range_slider.read(cx).value();
+    let brightness = view.vertical_slider.read(cx).value().start();
+
+    let controls = view
+        .card(
+            "controls.title",
+            v_flex()
+                .gap_3()
+                .child(
+                    v_flex()
+                        .gap_2()
+                        .child(div().child(t(cx, "controls.volume")))
+                        .child(
+                            h_flex()
+                                .gap_3()
+                                .items_center()
+                                .child(
+                                    view.focus_ring(
+                                        FocusTarget::Slider,
+                                        Slider::new(&view.slider_state),
+                                        window,
+                                        cx,
+                                    )
+                                    .flex_1(),
+                                )
+                                .child(
+                                    Input::new(&view.slider_input)
+                                        .small()
+                                        .w(px(72.))
+                                        .suffix("%"),
+                                ),
+                        )
+                        .child(match view.slider_input_error {
+                            Some(key) => div().text_sm().text_color(cx.theme().danger).child(
+                                t_args(cx, key, &[("min", &VOLUME_MIN), ("max", &VOLUME_MAX)]),
+                            ),
+                            None => div().text_sm().child(format!("{}%", slider_value as i32)),
+                        })
+                        .child(div().child(t(cx, "controls.price")))
+                        .child(Slider::new(&view.range_slider))
+                        .child(div().text_sm().child(t_args(
+                            cx,
+                            "controls.price_range",
+                            &[
+                                ("start", &(price.start() as i32)),
+                                ("end", &(price.end() as i32)),
+                            ],
+                        )))
+                        .child(
+                            h_flex()
+                                .gap_3()
+                                .items_center()
+                                .child(Slider::new(&view.vertical_slider).vertical().h(px(120.)))
+                                .child(div().text_sm().child(t_args(
+                                    cx,
+                                    "controls.brightness",
+                                    &[("value", &(brightness as i32))],
+                                ))),
+                        )
+                        .child(
+                            view.focus_ring(
+                                FocusTarget::Rating,
+                                Rating::new("rating")
+                                    .value(view.rating_value)
+                                    .max(5)
+                                    .on_click(cx.listener(|this, value, window, cx| {
+                                        this.apply(Change::Rating(*value), window, cx)
+                                    })),
+                                window,
+                                cx,
+                            ),
+                        )
+                        .child(
+                            h_flex()
+                                .gap_3()
+                                .items_center()
+                                .child(
+                                    view.focus_ring(
+                                        FocusTarget::HalfRating,
+                                        StarRating::new("half-rating")
+                                            .value(view.half_rating)
+                                            .half(true)
+                                            .on_change(cx.listener(|this, value, _, cx| {
+                                                this.half_rating = *value;
+                                                cx.notify();
+                                            })),
+                                        window,
+                                        cx,
+                                    ),
+                                )
+                                .child(div().text_sm().child(t_args(
+                                    cx,
+                                    "rating.half_value",
+                                    &[("value", &format_rating(view.half_rating))],
+                                ))),
+                        ),
+                )
+                .child(
+                    view.focus_ring(
+                        FocusTarget::Stepper,
+                        Stepper::new("stepper")
+                            .selected_index(view.stepper_step)
+                            .on_click(cx.listener(|this, step, window, cx| {
+                                if this.can_visit_step(*step) {
+                                    this.wizard.error = None;
+                                    this.apply(Change::Stepper(*step), window, cx)
+                                }
+                            }))
+                            .item(StepperItem::new().child(t(cx, "controls.step_login")))
+                            .item(StepperItem::new().child(t(cx, "controls.step_verify")))
+                            .item(StepperItem::new().child(t(cx, "controls.step_done"))),
+                        window,
+                        cx,
+                    ),
+                )
+                .child(render_wizard(view, window, cx)),
+            cx,
+        )
+        .into_any_element();
+
+    v_flex()
+        .gap_4()
+        .child(controls)
+        .child(render_ratings(view, cx))
+        .into_any_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{Entity, TestAppContext, VisualTestContext};
+
+    use super::*;
+    use crate::router::Route;
+    use crate::sections::Section;
+    use crate::test_support::open_gallery;
+
+    #[test]
+    fn volume_snaps_to_whole_steps() {
+        assert_eq!(snap_volume(42.), 42.);
+        assert_eq!(snap_volume(42.4), 42.);
+        assert_eq!(snap_volume(42.5), 43.);
+        assert_eq!(snap_volume(0.4), VOLUME_MIN);
+        assert_eq!(snap_volume(99.6), VOLUME_MAX);
+    }
+
+    #[test]
+    fn volume_is_clamped_to_its_bounds() {
+        assert_eq!(snap_volume(-0.4), VOLUME_MIN);
+        assert_eq!(snap_volume(-20.), VOLUME_MIN);
+        assert_eq!(snap_volume(100.4), VOLUME_MAX);
+        assert_eq!(snap_volume(250.), VOLUME_MAX);
+    }
+
+    /// Clears the volume input, focuses it and types `text`.
+    fn type_volume(gallery: &Entity<ComponentGallery>, text: &str, cx: &mut VisualTestContext) {
+        gallery.update_in(cx, |this, window, cx| {
+            this.slider_input
+                .update(cx, |state, cx| state.set_value("", window, cx));
+            this.slider_input.focus_handle(cx).focus(window);
+        });
+        cx.simulate_input(text);
+    }
+
+    fn volume_state(
+        gallery: &Entity<ComponentGallery>,
+        cx: &mut VisualTestContext,
+    ) -> (f32, String, Option<&'static str>) {
+        gallery.read_with(cx, |this, cx| {
+            (
+                this.slider_value,
+                this.slider_input.read(cx).value().to_string(),
+                this.slider_input_error,
+            )
+        })
+    }
+
+    #[gpui::test]
+    fn typed_volumes_are_clamped_and_invalid_ones_reverted_on_blur(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        gallery.update(cx, |this, cx| {
+            this.navigate(Route::section(Section::Controls), cx)
+        });
+        cx.run_until_parked();
+
+        type_volume(&gallery, "150", cx);
+        assert_eq!(volume_state(&gallery, cx), (VOLUME_MAX, "150".into(), None));
+
+        type_volume(&gallery, "4x", cx);
+        assert_eq!(
+            volume_state(&gallery, cx),
+            (4., "4x".into(), Some("controls.not_a_number"))
+        );
+
+        // Leaving the input puts back the slider's value and clears the error.
+        gallery.update_in(cx, |this, window, _| this.focus_handle.focus(window));
+        cx.run_until_parked();
+        assert_eq!(volume_state(&gallery, cx), (4., "4".into(), None));
+    }
+}
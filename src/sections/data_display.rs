@@ -4,6 +4,7 @@ use gpui_component::{
     group_box::GroupBox, h_flex, tag::Tag, v_flex,
 };
 
+use crate::i18n::t;
 use crate::ComponentGallery;
 
 pub fn render(
@@ -13,34 +14,34 @@ pub fn render(
 ) -> AnyElement {
     let is_compact = window.bounds().size.width <= px(680.);
     view.card(
-        "Data Display",
+        "data_display.title",
         v_flex()
             .gap_3()
             .child(
                 DescriptionList::new()
                     .columns(if is_compact { 1 } else { 2 })
-                    .item("Name", "GPUI Component", 1)
-                    .item("Platform", "OpenHarmony", 1)
-                    .item("Theme", "shadcn", 1)
-                    .item("Version", "0.1", 1),
+                    .item(t(cx, "data_display.name"), "GPUI Component", 1)
+                    .item(t(cx, "data_display.platform"), "OpenHarmony", 1)
+                    .item(t(cx, "data_display.theme"), "shadcn", 1)
+                    .item(t(cx, "data_display.version"), "0.1", 1),
             )
             .child(
-                GroupBox::new().title("Summary").child(
+                GroupBox::new().title(t(cx, "data_display.summary")).child(
                     v_flex()
                         .gap_2()
-                        .child("Compact card-like grouping")
+                        .child(t(cx, "data_display.grouping"))
                         .child(Divider::horizontal())
                         .child(if is_compact {
                             v_flex()
                                 .gap_2()
-                                .child(Tag::new().child("New"))
+                                .child(Tag::new().child(t(cx, "data_display.new")))
                                 .child(Badge::new().count(3))
                                 .child(Avatar::new().name("GP"))
                                 .into_any_element()
                         } else {
                             h_flex()
                                 .gap_2()
-                                .child(Tag::new().child("New"))
+                                .child(Tag::new().child(t(cx, "data_display.new")))
                                 .child(Badge::new().count(3))
                                 .child(Avatar::new().name("GP"))
                                 .into_any_element()
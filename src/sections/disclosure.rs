@@ -1,52 +1,143 @@
-use gpui::{AnyElement, IntoElement, ParentElement, Styled, Window};
+use gpui::{AnyElement, Context, IntoElement, ParentElement, Styled, Window};
 use gpui_component::{
     accordion::Accordion,
     button::{Button, ButtonVariants},
     collapsible::Collapsible,
+    h_flex,
+    switch::Switch,
     v_flex, Sizable,
 };
 
+use crate::focus::FocusTarget;
+use crate::i18n::t;
 use crate::ComponentGallery;
 
+/// Title and body keys of the accordion's items.
+const ACCORDION_ITEMS: [(&str, &str); 3] = [
+    ("disclosure.shadcn_title", "disclosure.shadcn_body"),
+    ("disclosure.ohos_title", "disclosure.ohos_body"),
+    ("disclosure.persist_title", "disclosure.persist_body"),
+];
+
+impl ComponentGallery {
+    /// Opens exactly the items in `open`, keeping only the first one when
+    /// the accordion allows a single open item.
+    fn set_accordion_open(&mut self, mut open: Vec<usize>, cx: &mut Context<Self>) {
+        open.retain(|ix| *ix < ACCORDION_ITEMS.len());
+        open.sort_unstable();
+        open.dedup();
+        if !self.accordion_multiple {
+            open.truncate(1);
+        }
+        self.accordion_open = open;
+        cx.notify();
+    }
+
+    pub(crate) fn set_accordion_multiple(&mut self, multiple: bool, cx: &mut Context<Self>) {
+        self.accordion_multiple = multiple;
+        self.set_accordion_open(self.accordion_open.clone(), cx);
+    }
+
+    pub(crate) fn expand_accordion(&mut self, cx: &mut Context<Self>) {
+        self.set_accordion_open((0..ACCORDION_ITEMS.len()).collect(), cx);
+    }
+
+    pub(crate) fn collapse_accordion(&mut self, cx: &mut Context<Self>) {
+        self.set_accordion_open(Vec::new(), cx);
+    }
+}
+
 pub fn render(
     view: &mut ComponentGallery,
-    _window: &mut Window,
+    window: &mut Window,
     cx: &mut gpui::Context<ComponentGallery>,
 ) -> AnyElement {
+    let all_open = view.accordion_open.len() == ACCORDION_ITEMS.len();
+
+    let mut accordion = Accordion::new("accordion")
+        .multiple(view.accordion_multiple)
+        .on_toggle_click(
+            cx.listener(|this, open: &[usize], _, cx| this.set_accordion_open(open.to_vec(), cx)),
+        );
+    for (ix, (title, body)) in ACCORDION_ITEMS.into_iter().enumerate() {
+        let open = view.accordion_open.contains(&ix);
+        accordion = accordion.item(|this| this.open(open).title(t(cx, title)).child(t(cx, body)));
+    }
+
     view.card(
-        "Disclosure",
+        "disclosure.title",
         v_flex()
             .gap_3()
             .child(
-                Accordion::new("accordion")
-                    .item(|this| {
-                        this.open(true)
-                            .title("Is it shadcn style?")
-                            .child("Yes, with subtle borders and muted backgrounds.")
-                    })
-                    .item(|this| {
-                        this.title("Works on OHOS")
-                            .child("This demo avoids gpui-component-story dependency.")
-                    }),
+                h_flex()
+                    .gap_2()
+                    .flex_wrap()
+                    .child(
+                        view.focus_ring(
+                            FocusTarget::AccordionMultiple,
+                            Switch::new("accordion-multiple")
+                                .checked(view.accordion_multiple)
+                                .label(t(cx, "disclosure.multiple"))
+                                .on_click(cx.listener(|this, checked, _, cx| {
+                                    this.set_accordion_multiple(*checked, cx)
+                                })),
+                            window,
+                            cx,
+                        ),
+                    )
+                    .child(
+                        view.focus_ring(
+                            FocusTarget::ExpandAll,
+                            Button::new("expand-all")
+                                .label(t(cx, "disclosure.expand_all"))
+                                .outline()
+                                .xsmall()
+                                .disabled(!view.accordion_multiple || all_open)
+                                .on_click(cx.listener(|this, _, _, cx| this.expand_accordion(cx))),
+                            window,
+                            cx,
+                        ),
+                    )
+                    .child(
+                        view.focus_ring(
+                            FocusTarget::CollapseAll,
+                            Button::new("collapse-all")
+                                .label(t(cx, "disclosure.collapse_all"))
+                                .outline()
+                                .xsmall()
+                                .disabled(view.accordion_open.is_empty())
+                                .on_click(
+                                    cx.listener(|this, _, _, cx| this.collapse_accordion(cx)),
+                                ),
+                            window,
+                            cx,
+                        ),
+                    ),
             )
+            .child(accordion)
             .child(
                 Collapsible::new()
                     .open(view.collapsible_open)
-                    .child("Tap to reveal details")
-                    .content("This content appears when the collapsible is open.")
+                    .child(t(cx, "disclosure.reveal"))
+                    .content(t(cx, "disclosure.revealed"))
                     .child(
-                        Button::new("toggle")
-                            .label(if view.collapsible_open {
-                                "Show less"
-                            } else {
-                                "Show more"
-                            })
-                            .xsmall()
-                            .link()
-                            .on_click(cx.listener(|this, _, _, cx| {
-                                this.collapsible_open = !this.collapsible_open;
-                                cx.notify();
-                            })),
+                        view.focus_ring(
+                            FocusTarget::Collapsible,
+                            Button::new("toggle")
+                                .label(if view.collapsible_open {
+                                    t(cx, "disclosure.show_less")
+                                } else {
+                                    t(cx, "disclosure.show_more")
+                                })
+                                .xsmall()
+                                .link()
+                                .on_click(cx.listener(|this, _, _, cx| {
+                                    this.collapsible_open = !this.collapsible_open;
+                                    cx.notify();
+                                })),
+                            window,
+                            cx,
+                        ),
                     ),
             ),
         cx,
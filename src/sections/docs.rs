@@ -0,0 +1,773 @@
+use std::collections::HashSet;
+use std::ops::Range;
+
+use gpui::prelude::FluentBuilder as _;
+use gpui::{
+    div, px, AnyElement, App, Entity, FontStyle, FontWeight, HighlightStyle,
+    InteractiveElement as _, InteractiveText, IntoElement, ParentElement, ScrollHandle,
+    SharedString, StatefulInteractiveElement as _, StrikethroughStyle, Styled, StyledText,
+    UnderlineStyle, Window,
+};
+use gpui_component::{
+    button::{Button, ButtonVariants},
+    h_flex,
+    scroll::{ScrollableElement as _, ScrollbarAxis},
+    text::markdown,
+    v_flex, ActiveTheme as _, IconName, Sizable, StyledExt as _,
+};
+use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+
+use crate::focus::FocusTarget;
+use crate::i18n::{t, t_args};
+use crate::ComponentGallery;
+
+const DOCS_ROOT: &str = "docs/";
+const HOME: &str = "docs/index.md";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinkTarget {
+    External(SharedString),
+    Anchor(SharedString),
+    Doc {
+        path: SharedString,
+        anchor: Option<SharedString>,
+    },
+    Broken,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum SpanKind {
+    Strong,
+    Emphasis,
+    Code,
+    Link(LinkTarget),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Span {
+    range: Range<usize>,
+    kind: SpanKind,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Inline {
+    text: String,
+    spans: Vec<Span>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Block {
+    Heading {
+        level: usize,
+        text: SharedString,
+        anchor: SharedString,
+    },
+    /// A run of blocks rendered by gpui-component's markdown view, which also
+    /// highlights fenced code by language.
+    Markdown(SharedString),
+    /// A paragraph or list item linking within the help pages. The markdown
+    /// view hands every link to the platform URL opener, so these are drawn
+    /// here to follow links in the viewer and flag broken ones.
+    Linked {
+        depth: usize,
+        marker: Option<SharedString>,
+        inline: Inline,
+    },
+}
+
+/// Turns heading text into the anchor used by `#fragment` links.
+fn slugify(text: &str) -> SharedString {
+    let mut slug = String::new();
+    for c in text.trim().chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if (c.is_whitespace() || c == '-') && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_matches('-').to_string().into()
+}
+
+fn is_external(href: &str) -> bool {
+    href.contains("://") || href.starts_with("mailto:")
+}
+
+fn heading_level(level: HeadingLevel) -> usize {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        _ => 4,
+    }
+}
+
+/// Splits `source` into headings, blocks with in-app links and markdown runs
+/// in between. Links are resolved with `resolve`.
+fn parse(source: &str, resolve: impl Fn(&str) -> LinkTarget) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    // Start of the markdown run that hasn't been pushed yet.
+    let mut run_start = None;
+    let mut depth = 0;
+    let mut block: Option<(Tag, usize)> = None;
+    let mut heading = String::new();
+    let mut linked = false;
+
+    for (event, range) in Parser::new_ext(source, Options::all()).into_offset_iter() {
+        match event {
+            Event::Start(tag) => {
+                if depth == 0 {
+                    block = Some((tag.clone(), range.start));
+                    heading.clear();
+                    linked = false;
+                }
+                if let Tag::Link { dest_url, .. } = &tag {
+                    linked |= !is_external(dest_url);
+                }
+                depth += 1;
+                continue;
+            }
+            Event::End(_) => depth -= 1,
+            Event::Text(text) | Event::Code(text) if depth > 0 => {
+                heading.push_str(&text);
+                continue;
+            }
+            _ if depth > 0 => continue,
+            // Rules and other leaf blocks stay in the markdown run.
+            _ => {
+                run_start.get_or_insert(range.start);
+                continue;
+            }
+        }
+        if depth > 0 {
+            continue;
+        }
+
+        let Some((tag, start)) = block.take() else {
+            continue;
+        };
+        let end = range.end;
+        let own = match tag {
+            Tag::Heading { level, .. } => {
+                let text = heading.trim().to_string();
+                vec![Block::Heading {
+                    level: heading_level(level),
+                    anchor: slugify(&text),
+                    text: text.into(),
+                }]
+            }
+            Tag::Paragraph | Tag::List(_) if linked => linked_blocks(&source[start..end], &resolve),
+            _ => {
+                run_start.get_or_insert(start);
+                continue;
+            }
+        };
+        if let Some(run_start) = run_start.take() {
+            blocks.push(Block::Markdown(
+                source[run_start..start].trim().to_string().into(),
+            ));
+        }
+        blocks.extend(own);
+    }
+    if let Some(run_start) = run_start {
+        blocks.push(Block::Markdown(
+            source[run_start..].trim().to_string().into(),
+        ));
+    }
+
+    blocks
+}
+
+/// Flattens a paragraph or list into one [`Block::Linked`] per paragraph or item.
+fn linked_blocks(source: &str, resolve: &impl Fn(&str) -> LinkTarget) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut inline = Inline::default();
+    let mut marker = None;
+    let mut open_spans: Vec<(usize, SpanKind)> = Vec::new();
+    let mut lists: Vec<Option<u64>> = Vec::new();
+
+    let mut flush = |inline: &mut Inline, marker: &mut Option<SharedString>, depth: usize| {
+        let inline = std::mem::take(inline);
+        if !inline.text.trim().is_empty() {
+            blocks.push(Block::Linked {
+                depth: depth.saturating_sub(1),
+                marker: marker.take(),
+                inline,
+            });
+        }
+    };
+
+    for event in Parser::new_ext(source, Options::all()) {
+        match event {
+            Event::Start(Tag::List(start)) => {
+                flush(&mut inline, &mut marker, lists.len());
+                lists.push(start);
+            }
+            Event::End(TagEnd::List(_)) => {
+                flush(&mut inline, &mut marker, lists.len());
+                lists.pop();
+            }
+            Event::Start(Tag::Item) => {
+                flush(&mut inline, &mut marker, lists.len());
+                marker = Some(match lists.last_mut() {
+                    Some(Some(n)) => {
+                        *n += 1;
+                        format!("{}.", *n - 1).into()
+                    }
+                    _ => "•".into(),
+                });
+            }
+            Event::End(TagEnd::Item) => flush(&mut inline, &mut marker, lists.len()),
+            Event::End(TagEnd::Paragraph) if lists.is_empty() => flush(&mut inline, &mut marker, 0),
+            Event::Start(Tag::Strong) => open_spans.push((inline.text.len(), SpanKind::Strong)),
+            Event::Start(Tag::Emphasis) => open_spans.push((inline.text.len(), SpanKind::Emphasis)),
+            Event::Start(Tag::Link { dest_url, .. }) => {
+                open_spans.push((inline.text.len(), SpanKind::Link(resolve(&dest_url))))
+            }
+            Event::End(TagEnd::Strong | TagEnd::Emphasis | TagEnd::Link) => {
+                if let Some((start, kind)) = open_spans.pop() {
+                    inline.spans.push(Span {
+                        range: start..inline.text.len(),
+                        kind,
+                    });
+                }
+            }
+            Event::Text(text) => inline.text.push_str(&text),
+            Event::Code(code) => {
+                let start = inline.text.len();
+                inline.text.push_str(&code);
+                inline.spans.push(Span {
+                    range: start..inline.text.len(),
+                    kind: SpanKind::Code,
+                });
+            }
+            Event::SoftBreak => inline.text.push(' '),
+            Event::HardBreak => inline.text.push('\n'),
+            _ => {}
+        }
+    }
+    flush(&mut inline, &mut marker, 0);
+
+    blocks
+}
+
+/// Anchors of every heading in `source`, so links can be checked before the
+/// document is split.
+fn anchors(source: &str) -> Vec<SharedString> {
+    let mut anchors = Vec::new();
+    let mut heading = None;
+    for event in Parser::new_ext(source, Options::all()) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => heading = Some(String::new()),
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(heading) = &mut heading {
+                    heading.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(heading) = heading.take() {
+                    anchors.push(slugify(&heading));
+                }
+            }
+            _ => {}
+        }
+    }
+    anchors
+}
+
+/// Joins `rel` onto `base_dir`, returning `None` when `..` escapes the root.
+fn join_path(base_dir: &str, rel: &str) -> Option<String> {
+    let mut parts = base_dir
+        .split('/')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>();
+    for segment in rel.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            segment => parts.push(segment),
+        }
+    }
+    Some(parts.join("/"))
+}
+
+fn resolve_link(
+    current: &str,
+    href: &str,
+    anchors: &[SharedString],
+    known: &HashSet<String>,
+) -> LinkTarget {
+    if is_external(href) {
+        return LinkTarget::External(href.to_string().into());
+    }
+
+    let (path, anchor) = match href.split_once('#') {
+        Some((path, anchor)) => (path, Some(anchor)),
+        None => (href, None),
+    };
+
+    if path.is_empty() {
+        return match anchor {
+            Some(anchor) if anchors.iter().any(|a| a == anchor) => {
+                LinkTarget::Anchor(anchor.to_string().into())
+            }
+            _ => LinkTarget::Broken,
+        };
+    }
+
+    let base_dir = if path.starts_with('/') {
+        ""
+    } else {
+        current.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("")
+    };
+    match join_path(base_dir, path) {
+        Some(path) if known.contains(&path) => LinkTarget::Doc {
+            path: path.into(),
+            anchor: anchor.map(|anchor| anchor.to_string().into()),
+        },
+        _ => LinkTarget::Broken,
+    }
+}
+
+/// Flattens possibly nested spans into the sorted, non-overlapping runs `StyledText` expects.
+fn flatten_highlights(
+    len: usize,
+    spans: impl IntoIterator<Item = (Range<usize>, HighlightStyle)>,
+) -> Vec<(Range<usize>, HighlightStyle)> {
+    let spans = spans.into_iter().collect::<Vec<_>>();
+    let mut bounds = spans
+        .iter()
+        .flat_map(|(range, _)| [range.start, range.end])
+        .chain([0, len])
+        .collect::<Vec<_>>();
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    bounds
+        .windows(2)
+        .filter_map(|pair| {
+            let range = pair[0]..pair[1];
+            let mut style: Option<HighlightStyle> = None;
+            for (span, highlight) in &spans {
+                if span.start <= range.start && range.end <= span.end {
+                    style
+                        .get_or_insert_with(HighlightStyle::default)
+                        .highlight(*highlight);
+                }
+            }
+            style.map(|style| (range, style))
+        })
+        .collect()
+}
+
+pub struct DocsViewer {
+    known: HashSet<String>,
+    path: SharedString,
+    blocks: Vec<Block>,
+    history: Vec<(SharedString, Option<SharedString>)>,
+    scroll: ScrollHandle,
+    error: Option<SharedString>,
+}
+
+impl DocsViewer {
+    pub fn new(cx: &App) -> Self {
+        let known = cx
+            .asset_source()
+            .list(DOCS_ROOT)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|path| path.ends_with(".md"))
+            .map(|path| path.to_string())
+            .collect();
+
+        let mut this = Self {
+            known,
+            path: HOME.into(),
+            blocks: Vec::new(),
+            history: Vec::new(),
+            scroll: ScrollHandle::new(),
+            error: None,
+        };
+        this.load(HOME.into(), cx);
+        this
+    }
+
+    fn load(&mut self, path: SharedString, cx: &App) {
+        let source = match cx.asset_source().load(&path) {
+            Ok(Some(bytes)) => String::from_utf8_lossy(&bytes).into_owned(),
+            Ok(None) => {
+                self.error = Some(t_args(cx, "docs.not_found", &[("path", &path)]));
+                return;
+            }
+            Err(err) => {
+                log::error!("failed to load {}: {:?}", path, err);
+                self.error = Some(t_args(cx, "docs.load_failed", &[("path", &path)]));
+                return;
+            }
+        };
+
+        let anchors = anchors(&source);
+        let blocks = parse(&source, |href| {
+            resolve_link(&path, href, &anchors, &self.known)
+        });
+
+        self.error = None;
+        self.path = path;
+        self.blocks = blocks;
+        self.scroll.set_offset(gpui::point(px(0.), px(0.)));
+    }
+
+    fn scroll_to_anchor(&self, anchor: &str) {
+        let ix = self.blocks.iter().position(
+            |block| matches!(block, Block::Heading { anchor: a, .. } if a.as_ref() == anchor),
+        );
+        if let Some(ix) = ix {
+            self.scroll.scroll_to_item(ix);
+        }
+    }
+
+    fn follow(&mut self, target: &LinkTarget, cx: &mut App) {
+        match target {
+            LinkTarget::External(url) => cx.open_url(url),
+            LinkTarget::Anchor(anchor) => self.scroll_to_anchor(anchor),
+            LinkTarget::Doc { path, anchor } => {
+                self.history.push((self.path.clone(), None));
+                self.load(path.clone(), cx);
+                if let Some(anchor) = anchor {
+                    self.scroll_to_anchor(anchor);
+                }
+            }
+            LinkTarget::Broken => {}
+        }
+    }
+
+    fn back(&mut self, cx: &App) {
+        if let Some((path, anchor)) = self.history.pop() {
+            self.load(path, cx);
+            if let Some(anchor) = anchor {
+                self.scroll_to_anchor(&anchor);
+            }
+        }
+    }
+
+    fn home(&mut self, cx: &App) {
+        if self.path.as_ref() != HOME {
+            self.history.push((self.path.clone(), None));
+            self.load(HOME.into(), cx);
+        }
+    }
+}
+
+fn render_inline(
+    id: (&'static str, usize),
+    inline: &Inline,
+    view: &Entity<ComponentGallery>,
+    cx: &App,
+) -> AnyElement {
+    let theme = cx.theme();
+    let mut link_ranges = Vec::new();
+    let mut link_targets = Vec::new();
+    let highlights = inline.spans.iter().map(|span| {
+        let style = match &span.kind {
+            SpanKind::Strong => HighlightStyle {
+                font_weight: Some(FontWeight::BOLD),
+                ..Default::default()
+            },
+            SpanKind::Emphasis => HighlightStyle {
+                font_style: Some(FontStyle::Italic),
+                ..Default::default()
+            },
+            SpanKind::Code => HighlightStyle {
+                background_color: Some(theme.secondary),
+                ..Default::default()
+            },
+            SpanKind::Link(target) => {
+                link_ranges.push(span.range.clone());
+                link_targets.push(target.clone());
+                if *target == LinkTarget::Broken {
+                    HighlightStyle {
+                        color: Some(theme.danger),
+                        underline: Some(UnderlineStyle {
+                            thickness: px(1.),
+                            color: Some(theme.danger),
+                            wavy: true,
+                        }),
+                        strikethrough: Some(StrikethroughStyle {
+                            thickness: px(1.),
+                            color: Some(theme.danger),
+                        }),
+                        ..Default::default()
+                    }
+                } else {
+                    HighlightStyle {
+                        color: Some(theme.link),
+                        underline: Some(UnderlineStyle {
+                            thickness: px(1.),
+                            color: Some(theme.link),
+                            wavy: false,
+                        }),
+                        ..Default::default()
+                    }
+                }
+            }
+        };
+        (span.range.clone(), style)
+    });
+    let highlights = flatten_highlights(inline.text.len(), highlights);
+
+    let view = view.clone();
+    InteractiveText::new(
+        id,
+        StyledText::new(inline.text.clone()).with_highlights(highlights),
+    )
+    .on_click(link_ranges, move |ix, _, cx| {
+        let target = link_targets[ix].clone();
+        view.update(cx, |this, cx| {
+            this.docs.follow(&target, cx);
+            cx.notify();
+        });
+    })
+    .into_any_element()
+}
+
+fn render_block(ix: usize, block: &Block, view: &Entity<ComponentGallery>, cx: &App) -> AnyElement {
+    let theme = cx.theme();
+    match block {
+        Block::Heading { level, text, .. } => div()
+            .pt_2()
+            .map(|this| match level {
+                1 => this.text_xl().font_bold(),
+                2 => this.text_lg().font_semibold(),
+                _ => this.text_base().font_semibold(),
+            })
+            .child(text.clone())
+            .into_any_element(),
+        Block::Markdown(source) => markdown(source.clone()).w_full().into_any_element(),
+        Block::Linked {
+            depth,
+            marker,
+            inline,
+        } => h_flex()
+            .items_start()
+            .gap_2()
+            .pl(px(16.) * *depth as f32)
+            .when_some(marker.clone(), |this, marker| {
+                this.child(div().text_color(theme.muted_foreground).child(marker))
+            })
+            .child(
+                div()
+                    .flex_1()
+                    .child(render_inline(("doc-text", ix), inline, view, cx)),
+            )
+            .into_any_element(),
+    }
+}
+
+pub fn render(
+    view: &mut ComponentGallery,
+    window: &mut Window,
+    cx: &mut gpui::Context<ComponentGallery>,
+) -> AnyElement {
+    let is_compact = window.bounds().size.width <= px(680.);
+    let entity = cx.entity();
+    let docs = &view.docs;
+
+    let outline = v_flex().gap_1().children(
+        docs.blocks
+            .iter()
+            .filter_map(|block| match block {
+                Block::Heading {
+                    level,
+                    text,
+                    anchor,
+                } if !is_compact || *level <= 2 => Some((*level, text.clone(), anchor.clone())),
+                _ => None,
+            })
+            .enumerate()
+            .map(|(ix, (level, text, anchor))| {
+                Button::new(("doc-outline", ix))
+                    .label(text)
+                    .ghost()
+                    .xsmall()
+                    .ml(px(8.) * (level - 1) as f32)
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        this.docs.scroll_to_anchor(&anchor);
+                        cx.notify();
+                    }))
+            }),
+    );
+
+    let content = div()
+        .flex_1()
+        .min_w_0()
+        .h(px(360.))
+        .border_1()
+        .border_color(cx.theme().border)
+        .rounded(cx.theme().radius)
+        .bg(cx.theme().background)
+        .child(match &docs.error {
+            Some(error) => div()
+                .p_3()
+                .text_color(cx.theme().danger)
+                .child(error.clone())
+                .into_any_element(),
+            None => v_flex()
+                .id("doc-content")
+                .size_full()
+                .p_3()
+                .gap_2()
+                .track_scroll(&docs.scroll)
+                .overflow_y_scroll()
+                .children(
+                    docs.blocks
+                        .iter()
+                        .enumerate()
+                        .map(|(ix, block)| render_block(ix, block, &entity, cx)),
+                )
+                .into_any_element(),
+        })
+        .scrollbar(&docs.scroll, ScrollbarAxis::Vertical);
+
+    view.card(
+        "docs.title",
+        v_flex()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .child(
+                        view.focus_ring(
+                            FocusTarget::DocsBack,
+                            Button::new("doc-back")
+                                .icon(IconName::ArrowLeft)
+                                .ghost()
+                                .small()
+                                .disabled(docs.history.is_empty())
+                                .on_click(cx.listener(|this, _, _, cx| {
+                                    this.docs.back(cx);
+                                    cx.notify();
+                                })),
+                            window,
+                            cx,
+                        ),
+                    )
+                    .child(
+                        view.focus_ring(
+                            FocusTarget::DocsHome,
+                            Button::new("doc-home")
+                                .label(t(cx, "docs.index"))
+                                .ghost()
+                                .small()
+                                .on_click(cx.listener(|this, _, _, cx| {
+                                    this.docs.home(cx);
+                                    cx.notify();
+                                })),
+                            window,
+                            cx,
+                        ),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(docs.path.clone()),
+                    ),
+            )
+            .child(if is_compact {
+                v_flex()
+                    .gap_2()
+                    .child(outline)
+                    .child(content)
+                    .into_any_element()
+            } else {
+                h_flex()
+                    .gap_3()
+                    .items_start()
+                    .child(div().w(px(180.)).child(outline))
+                    .child(content)
+                    .into_any_element()
+            }),
+        cx,
+    )
+    .into_any_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn known() -> HashSet<String> {
+        [
+            "docs/index.md",
+            "docs/components.md",
+            "docs/guides/theming.md",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect()
+    }
+
+    #[test]
+    fn resolves_links_relative_to_the_current_page() {
+        let known = known();
+        let anchors = [SharedString::from("help")];
+        let resolve = |href| resolve_link("docs/guides/theming.md", href, &anchors, &known);
+
+        assert_eq!(
+            resolve("../components.md#inputs"),
+            LinkTarget::Doc {
+                path: "docs/components.md".into(),
+                anchor: Some("inputs".into()),
+            }
+        );
+        assert_eq!(resolve("#help"), LinkTarget::Anchor("help".into()));
+        assert_eq!(resolve("#missing"), LinkTarget::Broken);
+        assert_eq!(resolve("changelog.md"), LinkTarget::Broken);
+        assert_eq!(resolve("../../../index.md"), LinkTarget::Broken);
+        assert_eq!(
+            resolve("https://gpui.rs"),
+            LinkTarget::External("https://gpui.rs".into())
+        );
+    }
+
+    #[test]
+    fn draws_headings_and_in_app_links_around_markdown_runs() {
+        let source = "# Help Page\n\nIntro with [a site](https://gpui.rs).\n\n```rust\nfn main() {}\n```\n\n- [Components](components.md)\n- Plain item\n\nSee [the top](#help-page).\n";
+        let blocks = parse(source, |href| match href.strip_prefix('#') {
+            Some(anchor) => LinkTarget::Anchor(anchor.to_string().into()),
+            None => LinkTarget::Broken,
+        });
+
+        assert_eq!(anchors(source), vec![SharedString::from("help-page")]);
+        assert_eq!(
+            blocks[0],
+            Block::Heading {
+                level: 1,
+                text: "Help Page".into(),
+                anchor: "help-page".into(),
+            }
+        );
+        assert_eq!(
+            blocks[1],
+            Block::Markdown(
+                "Intro with [a site](https://gpui.rs).\n\n```rust\nfn main() {}\n```".into()
+            )
+        );
+        let Block::Linked { marker, inline, .. } = &blocks[2] else {
+            panic!("expected a linked list item, got {:?}", blocks[2]);
+        };
+        assert_eq!(marker.as_deref(), Some("•"));
+        assert_eq!(inline.text, "Components");
+        assert_eq!(inline.spans[0].kind, SpanKind::Link(LinkTarget::Broken));
+        assert!(matches!(&blocks[3], Block::Linked { inline, .. } if inline.text == "Plain item"));
+        let Block::Linked { marker, inline, .. } = &blocks[4] else {
+            panic!("expected a linked paragraph, got {:?}", blocks[4]);
+        };
+        assert_eq!(*marker, None);
+        assert_eq!(inline.text, "See the top.");
+        assert_eq!(inline.spans[0].range, 4..11);
+        assert_eq!(blocks.len(), 5);
+    }
+}
@@ -1,58 +1,231 @@
-use gpui::{px, AnyElement, IntoElement, ParentElement, Styled, Window};
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, Context, IntoElement, ParentElement, Styled,
+    Window,
+};
 use gpui_component::{
     alert::Alert,
+    button::{Button, ButtonVariants},
     h_flex,
     progress::{Progress, ProgressCircle},
     skeleton::Skeleton,
     spinner::Spinner,
-    v_flex,
+    v_flex, ActiveTheme as _, Sizable,
 };
 
+use crate::focus::FocusTarget;
+use crate::i18n::{t, t_args};
+use crate::jobs::{Job, JobKind, JobStatus};
 use crate::ComponentGallery;
 
-pub fn render(
+fn status_text(job: &Job, cx: &Context<ComponentGallery>) -> AnyElement {
+    let (text, color) = match &job.status {
+        JobStatus::Running => (
+            t_args(cx, "jobs.percent", &[("n", &(job.progress as i32))]),
+            cx.theme().muted_foreground,
+        ),
+        JobStatus::Paused => (t(cx, "jobs.paused"), cx.theme().warning),
+        JobStatus::Cancelled => (t(cx, "jobs.cancelled"), cx.theme().muted_foreground),
+        JobStatus::Failed(reason) => (
+            t_args(cx, "jobs.failed", &[("reason", &t(cx, reason))]),
+            cx.theme().danger,
+        ),
+        JobStatus::Done(result) if result.is_empty() => (t(cx, "jobs.done"), cx.theme().success),
+        JobStatus::Done(result) => (
+            t_args(cx, "jobs.digest", &[("digest", result)]),
+            cx.theme().success,
+        ),
+    };
+    div()
+        .text_xs()
+        .text_color(color)
+        .child(text)
+        .into_any_element()
+}
+
+/// One row per job: progress, a spinner while it runs, and its controls.
+fn job_row(job: &Job, cx: &mut Context<ComponentGallery>) -> AnyElement {
+    let id = job.id;
+    let active = job.status.is_active();
+    h_flex()
+        .gap_3()
+        .items_center()
+        .child(
+            ProgressCircle::new(("job-circle", id))
+                .value(job.progress)
+                .small(),
+        )
+        .child(
+            v_flex()
+                .flex_1()
+                .gap_1()
+                .child(
+                    h_flex()
+                        .gap_2()
+                        .items_center()
+                        .child(div().text_sm().child(t(cx, job.kind.label_key())))
+                        .when(job.status == JobStatus::Running, |this| {
+                            this.child(Spinner::new().small())
+                        }),
+                )
+                .child(Progress::new(("job-progress", id)).value(job.progress))
+                .child(status_text(job, cx)),
+        )
+        .when(active, |this| {
+            this.child(
+                Button::new(("job-pause", id))
+                    .label(t(
+                        cx,
+                        if job.status == JobStatus::Paused {
+                            "jobs.resume"
+                        } else {
+                            "jobs.pause"
+                        },
+                    ))
+                    .ghost()
+                    .small()
+                    .on_click(cx.listener(move |this, _, _, cx| this.toggle_job_paused(id, cx))),
+            )
+            .child(
+                Button::new(("job-cancel", id))
+                    .label(t(cx, "common.cancel"))
+                    .ghost()
+                    .small()
+                    .on_click(cx.listener(move |this, _, _, cx| this.cancel_job(id, cx))),
+            )
+        })
+        .into_any_element()
+}
+
+impl ComponentGallery {
+    pub(crate) fn activate_job_target(&mut self, target: FocusTarget, cx: &mut Context<Self>) {
+        match target {
+            FocusTarget::JobDownload => self.start_job(JobKind::Download, cx),
+            FocusTarget::JobHash => self.start_job(JobKind::Hash, cx),
+            FocusTarget::JobUpload => self.start_job(JobKind::Upload, cx),
+            _ => {}
+        }
+    }
+}
+
+fn render_jobs(
     view: &mut ComponentGallery,
+    is_compact: bool,
     window: &mut Window,
-    cx: &mut gpui::Context<ComponentGallery>,
+    cx: &mut Context<ComponentGallery>,
 ) -> AnyElement {
-    let is_compact = window.bounds().size.width <= px(680.);
+    let buttons = [
+        (FocusTarget::JobDownload, "jobs.start_download"),
+        (FocusTarget::JobHash, "jobs.start_hash"),
+        (FocusTarget::JobUpload, "jobs.start_upload"),
+    ];
+    let has_finished = view.jobs.iter().any(|job| !job.status.is_active());
+    let rows = view
+        .jobs
+        .iter()
+        .map(|job| job_row(job, cx))
+        .collect::<Vec<_>>();
+
     view.card(
-        "Status & Feedback",
+        "jobs.title",
         v_flex()
             .gap_3()
-            .child(Alert::info("alert", "This is an info alert.").title("Heads up"))
-            .child(if is_compact {
-                v_flex()
-                    .gap_2()
-                    .child(Progress::new("progress").value(view.progress_value))
-                    .child(ProgressCircle::new("progress-circle").value(view.progress_value))
-                    .into_any_element()
-            } else {
-                h_flex()
-                    .gap_3()
-                    .items_center()
-                    .child(Progress::new("progress").value(view.progress_value))
-                    .child(ProgressCircle::new("progress-circle").value(view.progress_value))
-                    .into_any_element()
+            .child(
+                if is_compact {
+                    v_flex().gap_2()
+                } else {
+                    h_flex().gap_2()
+                }
+                .children(buttons.into_iter().map(|(target, label)| {
+                    view.focus_ring(
+                        target,
+                        Button::new(label)
+                            .label(t(cx, label))
+                            .outline()
+                            .when(is_compact, |this| this.w_full())
+                            .on_click(cx.listener(move |this, _, _, cx| {
+                                this.activate_job_target(target, cx)
+                            })),
+                        window,
+                        cx,
+                    )
+                    .when(is_compact, |this| this.w_full())
+                }))
+                .when(has_finished, |this| {
+                    this.child(
+                        Button::new("clear-jobs")
+                            .label(t(cx, "jobs.clear_finished"))
+                            .ghost()
+                            .on_click(cx.listener(|this, _, _, cx| this.clear_finished_jobs(cx))),
+                    )
+                }),
+            )
+            .when(view.jobs.is_empty(), |this| {
+                this.child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(t(cx, "jobs.empty")),
+                )
             })
-            .child(if is_compact {
-                v_flex()
-                    .gap_2()
-                    .items_center()
-                    .child(Spinner::new())
-                    .child(Skeleton::new().w(gpui::px(140.)).h(gpui::px(12.)))
-                    .child(Skeleton::new().w(gpui::px(64.)).h(gpui::px(36.)))
-                    .into_any_element()
-            } else {
-                h_flex()
-                    .gap_3()
-                    .items_center()
-                    .child(Spinner::new())
-                    .child(Skeleton::new().w(gpui::px(140.)).h(gpui::px(12.)))
-                    .child(Skeleton::new().w(gpui::px(64.)).h(gpui::px(36.)))
-                    .into_any_element()
-            }),
+            .children(rows),
         cx,
     )
     .into_any_element()
 }
+
+pub fn render(
+    view: &mut ComponentGallery,
+    window: &mut Window,
+    cx: &mut gpui::Context<ComponentGallery>,
+) -> AnyElement {
+    let is_compact = window.bounds().size.width <= px(680.);
+    let feedback = view
+        .card(
+            "feedback.title",
+            v_flex()
+                .gap_3()
+                .child(
+                    Alert::info("alert", t(cx, "feedback.alert"))
+                        .title(t(cx, "feedback.alert_title")),
+                )
+                .child(if is_compact {
+                    v_flex()
+                        .gap_2()
+                        .child(Progress::new("progress").value(view.progress_value))
+                        .child(ProgressCircle::new("progress-circle").value(view.progress_value))
+                        .into_any_element()
+                } else {
+                    h_flex()
+                        .gap_3()
+                        .items_center()
+                        .child(Progress::new("progress").value(view.progress_value))
+                        .child(ProgressCircle::new("progress-circle").value(view.progress_value))
+                        .into_any_element()
+                })
+                .child(if is_compact {
+                    v_flex()
+                        .gap_2()
+                        .items_center()
+                        .child(Spinner::new())
+                        .child(Skeleton::new().w(gpui::px(140.)).h(gpui::px(12.)))
+                        .child(Skeleton::new().w(gpui::px(64.)).h(gpui::px(36.)))
+                        .into_any_element()
+                } else {
+                    h_flex()
+                        .gap_3()
+                        .items_center()
+                        .child(Spinner::new())
+                        .child(Skeleton::new().w(gpui::px(140.)).h(gpui::px(12.)))
+                        .child(Skeleton::new().w(gpui::px(64.)).h(gpui::px(36.)))
+                        .into_any_element()
+                }),
+            cx,
+        )
+        .into_any_element();
+
+    v_flex()
+        .gap_4()
+        .child(feedback)
+        .child(render_jobs(view, is_compact, window, cx))
+        .into_any_element()
+}
@@ -1,20 +1,122 @@
-use gpui::{div, px, AnyElement, IntoElement, ParentElement, Styled, Window};
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, App, Entity, IntoElement, ParentElement,
+    SharedString, Styled, Window,
+};
 use gpui_component::{
+    button::{Button, ButtonVariants},
     checkbox::Checkbox,
     clipboard::Clipboard,
     color_picker::ColorPicker,
-    date_picker::DatePicker,
     form::{field, v_form},
     h_flex,
-    input::Input,
+    input::{Input, InputState},
+    notification::NotificationType,
+    popover::Popover,
     radio::Radio,
     select::Select,
     switch::Switch,
-    v_flex, ActiveTheme as _, Sizable, WindowExt as _,
+    v_flex, ActiveTheme as _, IconName, Sizable, WindowExt as _,
 };
 
+use crate::clipboard;
+use crate::focus::FocusTarget;
+use crate::history::Change;
+use crate::i18n::{t, t_args};
+use crate::notifications;
 use crate::ComponentGallery;
 
+pub fn select_options(cx: &App) -> Vec<SharedString> {
+    ["forms.option_a", "forms.option_b", "forms.option_c"]
+        .into_iter()
+        .map(|key| t(cx, key))
+        .collect()
+}
+
+pub fn role_options(cx: &App) -> Vec<SharedString> {
+    ["forms.role_admin", "forms.role_editor", "forms.role_viewer"]
+        .into_iter()
+        .map(|key| t(cx, key))
+        .collect()
+}
+
+/// Copy, Paste and a clipboard history popover for an input's suffix. Paste and
+/// history entries insert at the cursor, replacing any selection.
+fn clipboard_suffix(id: &'static str, state: &Entity<InputState>, cx: &App) -> impl IntoElement {
+    let history = clipboard::history(cx);
+
+    h_flex()
+        .gap_1()
+        .child(
+            Clipboard::new(id)
+                .value_fn({
+                    let state = state.clone();
+                    move |_, cx| state.read(cx).value()
+                })
+                .on_copied(|value, window, cx| {
+                    clipboard::copy(value.clone(), cx);
+                    let message = t_args(cx, "forms.copied", &[("value", &value)]);
+                    notifications::push(NotificationType::Success, message, window, cx)
+                }),
+        )
+        .child(
+            Button::new((id, 0usize))
+                .label(t(cx, "forms.paste"))
+                .ghost()
+                .xsmall()
+                .on_click({
+                    let state = state.clone();
+                    move |_, window, cx| match clipboard::paste(cx) {
+                        Some(text) => state.update(cx, |state, cx| state.insert(text, window, cx)),
+                        None => {
+                            let message = t(cx, "forms.clipboard_empty");
+                            notifications::push(NotificationType::Info, message, window, cx)
+                        }
+                    }
+                }),
+        )
+        .child(
+            Popover::new((id, 1usize))
+                .trigger(
+                    Button::new((id, 2usize))
+                        .icon(IconName::ChevronDown)
+                        .ghost()
+                        .xsmall()
+                        .tooltip(t(cx, "forms.clipboard_history")),
+                )
+                .content({
+                    let state = state.clone();
+                    move |_, _, cx| {
+                        v_flex()
+                            .gap_1()
+                            .p_2()
+                            .min_w(px(200.))
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(t(cx, "forms.clipboard_history")),
+                            )
+                            .when(history.is_empty(), |this| {
+                                this.child(div().text_sm().child(t(cx, "forms.history_empty")))
+                            })
+                            .children(history.iter().enumerate().map(|(ix, text)| {
+                                let (state, text) = (state.clone(), text.clone());
+                                Button::new(("clipboard-entry", ix))
+                                    .label(text.clone())
+                                    .ghost()
+                                    .small()
+                                    .w_full()
+                                    .on_click(move |_, window, cx| {
+                                        state.update(cx, |state, cx| {
+                                            state.insert(text.clone(), window, cx)
+                                        })
+                                    })
+                            }))
+                    }
+                }),
+        )
+}
+
 pub fn render(
     view: &mut ComponentGallery,
     window: &mut Window,
@@ -22,37 +124,45 @@ pub fn render(
 ) -> AnyElement {
     let is_compact = window.bounds().size.width <= px(680.);
     view.card(
-        "Form Inputs",
+        "forms.title",
         v_flex()
             .gap_3()
             .child(Input::new(&view.input_state).cleanable(true))
             .child(Input::new(&view.textarea_state).h(gpui::px(120.)))
             .child(
                 Select::new(&view.select_state)
-                    .placeholder("Select an option")
-                    .search_placeholder("Search options")
+                    .placeholder(t(cx, "forms.select_placeholder"))
+                    .search_placeholder(t(cx, "forms.search_placeholder"))
                     .cleanable(true),
             )
             .child(if is_compact {
                 v_flex()
                     .gap_2()
                     .child(
-                        Checkbox::new("agree")
-                            .label("Agree")
-                            .checked(view.checkbox_checked)
-                            .on_click(cx.listener(|this, checked, _, cx| {
-                                this.checkbox_checked = *checked;
-                                cx.notify();
-                            })),
+                        view.focus_ring(
+                            FocusTarget::Agree,
+                            Checkbox::new("agree")
+                                .label(t(cx, "forms.agree"))
+                                .checked(view.checkbox_checked)
+                                .on_click(cx.listener(|this, checked, window, cx| {
+                                    this.apply(Change::Checkbox(*checked), window, cx)
+                                })),
+                            window,
+                            cx,
+                        ),
                     )
                     .child(
-                        Switch::new("switch")
-                            .label("Enable")
-                            .checked(view.switch_on)
-                            .on_click(cx.listener(|this, checked, _, cx| {
-                                this.switch_on = *checked;
-                                cx.notify();
-                            })),
+                        view.focus_ring(
+                            FocusTarget::Enable,
+                            Switch::new("switch")
+                                .label(t(cx, "forms.enable"))
+                                .checked(view.switch_on)
+                                .on_click(cx.listener(|this, checked, window, cx| {
+                                    this.apply(Change::Switch(*checked), window, cx)
+                                })),
+                            window,
+                            cx,
+                        ),
                     )
                     .into_any_element()
             } else {
@@ -60,92 +170,105 @@ pub fn render(
                     .gap_3()
                     .items_center()
                     .child(
-                        Checkbox::new("agree")
-                            .label("Agree")
-                            .checked(view.checkbox_checked)
-                            .on_click(cx.listener(|this, checked, _, cx| {
-                                this.checkbox_checked = *checked;
-                                cx.notify();
-                            })),
-                    )
-                    .child(
-                        Switch::new("switch")
-                            .label("Enable")
-                            .checked(view.switch_on)
-                            .on_click(cx.listener(|this, checked, _, cx| {
-                                this.switch_on = *checked;
-                                cx.notify();
-                            })),
+                        view.focus_ring(
+                            FocusTarget::Agree,
+                            Checkbox::new("agree")
+                                .label(t(cx, "forms.agree"))
+                                .checked(view.checkbox_checked)
+                                .on_click(cx.listener(|this, checked, window, cx| {
+                                    this.apply(Change::Checkbox(*checked), window, cx)
+                                })),
+                            window,
+                            cx,
+                        ),
                     )
-                    .into_any_element()
-            })
-            .child(
-                v_flex()
-                    .gap_2()
                     .child(
-                        Radio::new("radio1")
-                            .label("Option 1")
-                            .checked(view.radio_checked)
-                            .on_click(cx.listener(|this, v, _, cx| {
-                                this.radio_checked = *v;
-                                cx.notify();
-                            })),
+                        view.focus_ring(
+                            FocusTarget::Enable,
+                            Switch::new("switch")
+                                .label(t(cx, "forms.enable"))
+                                .checked(view.switch_on)
+                                .on_click(cx.listener(|this, checked, window, cx| {
+                                    this.apply(Change::Switch(*checked), window, cx)
+                                })),
+                            window,
+                            cx,
+                        ),
                     )
-                    .child(
-                        Radio::new("radio2")
-                            .label("Option 2")
-                            .checked(!view.radio_checked)
-                            .on_click(cx.listener(|this, v: &bool, _, cx| {
-                                this.radio_checked = !*v;
-                                cx.notify();
-                            })),
-                    ),
-            )
-            .child(if is_compact {
-                v_flex()
-                    .gap_2()
-                    .child(DatePicker::new(&view.date_picker))
-                    .child(ColorPicker::new(&view.color_picker).small())
-                    .into_any_element()
-            } else {
-                h_flex()
-                    .gap_3()
-                    .child(DatePicker::new(&view.date_picker))
-                    .child(ColorPicker::new(&view.color_picker).small())
                     .into_any_element()
             })
             .child(
-                Input::new(&view.input_state).suffix(
-                    Clipboard::new("clipboard")
-                        .value_fn({
-                            let state = view.input_state.clone();
-                            move |_, cx| state.read(cx).value()
-                        })
-                        .on_copied(|value, window, cx| {
-                            window.push_notification(format!("Copied: {}", value), cx)
-                        }),
+                view.focus_ring(
+                    FocusTarget::Radio,
+                    v_flex()
+                        .gap_2()
+                        .child(
+                            Radio::new("radio1")
+                                .label(t(cx, "forms.option_1"))
+                                .checked(view.radio_checked)
+                                .on_click(cx.listener(|this, v, window, cx| {
+                                    this.apply(Change::Radio(*v), window, cx)
+                                })),
+                        )
+                        .child(
+                            Radio::new("radio2")
+                                .label(t(cx, "forms.option_2"))
+                                .checked(!view.radio_checked)
+                                .on_click(cx.listener(|this, v: &bool, window, cx| {
+                                    this.apply(Change::Radio(!*v), window, cx)
+                                })),
+                        ),
+                    window,
+                    cx,
                 ),
             )
+            .child(ColorPicker::new(&view.color_picker).small())
+            .child(Input::new(&view.input_state).suffix(clipboard_suffix(
+                "clipboard",
+                &view.input_state,
+                cx,
+            )))
             .child(
                 v_form()
                     .label_width(gpui::px(120.))
-                    .child(field().label("Name").child(Input::new(&view.form_name)))
-                    .child(field().label("Email").child(Input::new(&view.form_email)))
-                    .child(field().label("Role").child(Select::new(&view.form_role)))
+                    .child(field().label(t(cx, "forms.name")).child(
+                        Input::new(&view.form_name).suffix(clipboard_suffix(
+                            "form-name",
+                            &view.form_name,
+                            cx,
+                        )),
+                    ))
+                    .child(field().label(t(cx, "forms.email")).child(
+                        Input::new(&view.form_email).suffix(clipboard_suffix(
+                            "form-email",
+                            &view.form_email,
+                            cx,
+                        )),
+                    ))
                     .child(
-                        field().label("Subscribe").child(
-                            Switch::new("subscribe")
-                                .checked(view.form_subscribe)
-                                .label("Email updates")
-                                .on_click(cx.listener(|this, checked, _, cx| {
-                                    this.form_subscribe = *checked;
-                                    cx.notify();
-                                })),
+                        field()
+                            .label(t(cx, "forms.role"))
+                            .child(Select::new(&view.form_role)),
+                    )
+                    .child(
+                        field().label(t(cx, "forms.subscribe")).child(
+                            view.focus_ring(
+                                FocusTarget::FormSubscribe,
+                                Switch::new("subscribe")
+                                    .checked(view.form_subscribe)
+                                    .label(t(cx, "forms.email_updates"))
+                                    .on_click(cx.listener(|this, checked, _, cx| {
+                                        this.form_subscribe = *checked;
+                                        cx.notify();
+                                    })),
+                                window,
+                                cx,
+                            ),
                         ),
                     )
                     .child(
                         field()
-                            .label("Notes")
+                            .label(t(cx, "forms.notes"))
                             .child(Input::new(&view.form_notes).h(gpui::px(90.))),
                     ),
             )
@@ -153,14 +276,21 @@ pub fn render(
                 div()
                     .text_sm()
                     .text_color(cx.theme().muted_foreground)
-                    .child(format!(
-                        "Input: {} | Selected: {}",
-                        view.input_state.read(cx).value(),
-                        view.select_state
-                            .read(cx)
-                            .selected_value()
-                            .cloned()
-                            .unwrap_or_else(|| "(none)".into())
+                    .child(t_args(
+                        cx,
+                        "forms.summary",
+                        &[
+                            ("input", &view.input_state.read(cx).value()),
+                            (
+                                "selected",
+                                &view
+                                    .select_state
+                                    .read(cx)
+                                    .selected_value()
+                                    .cloned()
+                                    .unwrap_or_else(|| t(cx, "forms.none")),
+                            ),
+                        ],
                     )),
             ),
         cx,
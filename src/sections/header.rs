@@ -1,27 +1,134 @@
-use gpui::{div, Window};
+use gpui::{div, prelude::FluentBuilder as _, px, Window};
 use gpui::{AnyElement, IntoElement, ParentElement, Styled};
-use gpui_component::{input::Input, v_flex, ActiveTheme as _, IconName, StyledExt as _};
+use gpui_component::{
+    badge::Badge,
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::Input,
+    select::Select,
+    v_flex, ActiveTheme as _, IconName, Sizable, StyledExt as _,
+};
 
+use crate::focus::FocusTarget;
+use crate::history::{Redo, Undo};
+use crate::i18n::t;
+use crate::keymap;
+use crate::notifications;
 use crate::ComponentGallery;
 
 pub fn render(
     view: &mut ComponentGallery,
-    _: &mut Window,
+    window: &mut Window,
     cx: &mut gpui::Context<ComponentGallery>,
 ) -> AnyElement {
+    let is_compact = window.bounds().size.width <= px(680.);
     v_flex()
         .gap_2()
         .child(
-            div()
-                .text_xl()
-                .font_semibold()
-                .child("GPUI Component Gallery"),
+            h_flex()
+                .gap_2()
+                .items_center()
+                .justify_between()
+                .child(
+                    h_flex()
+                        .gap_1()
+                        .items_center()
+                        .when(is_compact, |this| {
+                            this.child(
+                                view.focus_ring(
+                                    FocusTarget::NavMenu,
+                                    Button::new("nav-menu")
+                                        .icon(IconName::Menu)
+                                        .ghost()
+                                        .small()
+                                        .tooltip(t(cx, "sidebar.open_menu"))
+                                        .on_click(cx.listener(|this, _, window, cx| {
+                                            this.open_nav_drawer(window, cx)
+                                        })),
+                                    window,
+                                    cx,
+                                ),
+                            )
+                        })
+                        .child(div().text_xl().font_semibold().child(t(cx, "header.title"))),
+                )
+                .child(
+                    h_flex()
+                        .gap_2()
+                        .child(
+                            view.focus_ring(
+                                FocusTarget::Undo,
+                                Button::new("undo")
+                                    .label(t(cx, "history.undo"))
+                                    .ghost()
+                                    .small()
+                                    .disabled(!view.history.can_undo())
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.undo(&Undo, window, cx)
+                                    })),
+                                window,
+                                cx,
+                            ),
+                        )
+                        .child(
+                            view.focus_ring(
+                                FocusTarget::Redo,
+                                Button::new("redo")
+                                    .label(t(cx, "history.redo"))
+                                    .ghost()
+                                    .small()
+                                    .disabled(!view.history.can_redo())
+                                    .on_click(cx.listener(|this, _, window, cx| {
+                                        this.redo(&Redo, window, cx)
+                                    })),
+                                window,
+                                cx,
+                            ),
+                        )
+                        .child(
+                            view.focus_ring(
+                                FocusTarget::Notifications,
+                                Badge::new().count(notifications::unread_count(cx)).child(
+                                    Button::new("notifications")
+                                        .icon(IconName::Bell)
+                                        .ghost()
+                                        .small()
+                                        .tooltip(t(cx, "notifications.title"))
+                                        .on_click(|_, window, cx| {
+                                            notifications::open_center(window, cx)
+                                        }),
+                                ),
+                                window,
+                                cx,
+                            ),
+                        )
+                        .child(
+                            view.focus_ring(
+                                FocusTarget::Shortcuts,
+                                Button::new("shortcuts")
+                                    .icon(IconName::Info)
+                                    .ghost()
+                                    .small()
+                                    .tooltip(t(cx, "keymap.title"))
+                                    .on_click(|_, window, cx| {
+                                        keymap::open_shortcuts_sheet(window, cx)
+                                    }),
+                                window,
+                                cx,
+                            ),
+                        )
+                        .child(
+                            div()
+                                .w(gpui::px(128.))
+                                .child(Select::new(&view.language_select).small()),
+                        ),
+                ),
         )
         .child(
             div()
                 .text_sm()
                 .text_color(cx.theme().muted_foreground)
-                .child("Mobile-style shadcn UI demo on OpenHarmony"),
+                .child(t(cx, "header.subtitle")),
         )
         .child(
             Input::new(&view.input_state)
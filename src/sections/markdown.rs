@@ -0,0 +1,211 @@
+use std::time::{Duration, Instant};
+
+use gpui::{div, px, AnyElement, Context, IntoElement, ParentElement, Styled, Window};
+use gpui_component::{
+    h_flex,
+    input::Input,
+    scroll::ScrollableElement as _,
+    tab::{Tab, TabBar},
+    text::markdown,
+    v_flex, ActiveTheme as _,
+};
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+
+use crate::focus::FocusTarget;
+use crate::i18n::{t, t_args};
+use crate::ComponentGallery;
+
+/// Delay between the last edit and re-rendering the preview.
+const PREVIEW_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Documents that take longer than one frame to parse are flagged.
+const SLOW_PARSE: Duration = Duration::from_millis(16);
+
+pub const SAMPLE: &str = "# Markdown Playground\n\nEdit the source and the preview follows.\n\n## Lists\n\n- **Bold** and _italic_\n- `inline code`\n- [Links](https://gpui.rs)\n\n## Code\n\n```rust\nfn main() {\n    println!(\"Hello, OpenHarmony!\");\n}\n```\n\n> Quotes render too.\n";
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MarkdownStats {
+    /// How long this pass took. The preview parses the source again itself,
+    /// so this is an estimate of its parse time, not a measurement of it.
+    pub parse_time: Duration,
+    /// Words of text outside code blocks.
+    pub words: usize,
+    pub blocks: usize,
+    pub headings: usize,
+    pub code_blocks: usize,
+}
+
+/// Runs a full parse pass over `source`, counting its words and blocks and
+/// timing the pass.
+pub fn analyze(source: &str) -> MarkdownStats {
+    let start = Instant::now();
+    let mut stats = MarkdownStats::default();
+    let mut in_code_block = false;
+    for event in Parser::new_ext(source, Options::all()) {
+        let tag = match event {
+            Event::Start(tag) => tag,
+            Event::End(TagEnd::CodeBlock) => {
+                in_code_block = false;
+                continue;
+            }
+            Event::Text(text) | Event::Code(text) if !in_code_block => {
+                stats.words += text.split_whitespace().count();
+                continue;
+            }
+            _ => continue,
+        };
+        match tag {
+            Tag::Heading { .. } => {
+                stats.blocks += 1;
+                stats.headings += 1;
+            }
+            Tag::CodeBlock(_) => {
+                in_code_block = true;
+                stats.blocks += 1;
+                stats.code_blocks += 1;
+            }
+            Tag::Paragraph | Tag::BlockQuote(_) | Tag::List(_) | Tag::Table(_) => {
+                stats.blocks += 1;
+            }
+            _ => {}
+        }
+    }
+    stats.parse_time = start.elapsed();
+    stats
+}
+
+impl ComponentGallery {
+    /// Re-renders the preview once the source has been idle for [`PREVIEW_DEBOUNCE`].
+    pub(crate) fn schedule_markdown_preview(&mut self, cx: &mut Context<Self>) {
+        self.markdown_debounce = cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(PREVIEW_DEBOUNCE).await;
+            _ = this.update(cx, |this, cx| {
+                let source = this.markdown_source.read(cx).value();
+                this.markdown_stats = analyze(&source);
+                this.markdown_preview = source;
+                cx.notify();
+            });
+        });
+    }
+}
+
+pub fn render(
+    view: &mut ComponentGallery,
+    window: &mut Window,
+    cx: &mut gpui::Context<ComponentGallery>,
+) -> AnyElement {
+    let is_compact = window.bounds().size.width <= px(680.);
+    let stats = view.markdown_stats;
+
+    let editor = Input::new(&view.markdown_source).h(px(320.));
+    let preview = v_flex()
+        .h(px(320.))
+        .p_3()
+        .border_1()
+        .border_color(cx.theme().border)
+        .rounded(cx.theme().radius)
+        .bg(cx.theme().background)
+        .overflow_y_scrollbar()
+        .child(markdown(view.markdown_preview.clone()).w_full());
+
+    view.card(
+        "markdown.title",
+        v_flex()
+            .gap_3()
+            .child(if is_compact {
+                v_flex()
+                    .gap_2()
+                    .child(
+                        view.focus_ring(
+                            FocusTarget::MarkdownTabs,
+                            TabBar::new("markdown-tabs")
+                                .w_full()
+                                .selected_index(view.markdown_tab)
+                                .on_click(cx.listener(|this, ix, _, cx| {
+                                    this.markdown_tab = *ix;
+                                    cx.notify();
+                                }))
+                                .child(Tab::new().label(t(cx, "markdown.edit")))
+                                .child(Tab::new().label(t(cx, "markdown.preview"))),
+                            window,
+                            cx,
+                        ),
+                    )
+                    .child(if view.markdown_tab == 0 {
+                        editor.into_any_element()
+                    } else {
+                        preview.into_any_element()
+                    })
+                    .into_any_element()
+            } else {
+                h_flex()
+                    .gap_3()
+                    .items_start()
+                    .child(div().flex_1().min_w_0().child(editor))
+                    .child(div().flex_1().min_w_0().child(preview))
+                    .into_any_element()
+            })
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(if stats.parse_time > SLOW_PARSE {
+                        cx.theme().danger
+                    } else {
+                        cx.theme().muted_foreground
+                    })
+                    .child(t_args(
+                        cx,
+                        "markdown.stats",
+                        &[
+                            (
+                                "ms",
+                                &format!("{:.2}", stats.parse_time.as_secs_f64() * 1000.),
+                            ),
+                            ("words", &stats.words),
+                            ("blocks", &stats.blocks),
+                            ("headings", &stats.headings),
+                            ("code_blocks", &stats.code_blocks),
+                        ],
+                    )),
+            ),
+        cx,
+    )
+    .into_any_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(source: &str) -> (usize, usize, usize, usize) {
+        let stats = analyze(source);
+        (stats.words, stats.blocks, stats.headings, stats.code_blocks)
+    }
+
+    #[test]
+    fn the_sample_counts_every_kind_of_block() {
+        // Three headings, a paragraph, a list, a code block, and a quote
+        // holding a paragraph.
+        assert_eq!(counts(SAMPLE), (20, 8, 3, 1));
+    }
+
+    #[test]
+    fn words_skip_code_blocks_but_count_inline_code() {
+        assert_eq!(counts("one `two` **three**"), (3, 1, 0, 0));
+        assert_eq!(
+            counts("```\nnot counted here\n```\n\n    indented code too\n"),
+            (0, 2, 0, 2)
+        );
+    }
+
+    #[test]
+    fn headings_of_every_level_are_counted() {
+        assert_eq!(counts("# One\n\n### Three\n\nSetext\n---\n"), (3, 3, 3, 0));
+    }
+
+    #[test]
+    fn an_empty_document_has_nothing() {
+        assert_eq!(counts(""), (0, 0, 0, 0));
+        assert_eq!(counts("  \n\n"), (0, 0, 0, 0));
+    }
+}
@@ -1,10 +1,113 @@
+use gpui::{AnyElement, Context, Window};
+
+use crate::ComponentGallery;
+
 pub mod advanced;
 pub mod buttons;
 pub mod controls;
 pub mod data_display;
 pub mod disclosure;
+pub mod docs;
 pub mod feedback;
 pub mod forms;
 pub mod header;
+pub mod markdown;
 pub mod navigation;
 pub mod overlays;
+pub mod sheets;
+
+/// The top-level sections of the gallery, in page order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Section {
+    Header,
+    Buttons,
+    Forms,
+    Feedback,
+    Controls,
+    Navigation,
+    Disclosure,
+    DataDisplay,
+    Overlays,
+    Sheets,
+    Markdown,
+    Docs,
+    Advanced,
+}
+
+impl Section {
+    pub const ALL: [Section; 13] = [
+        Section::Header,
+        Section::Buttons,
+        Section::Forms,
+        Section::Feedback,
+        Section::Controls,
+        Section::Navigation,
+        Section::Disclosure,
+        Section::DataDisplay,
+        Section::Overlays,
+        Section::Sheets,
+        Section::Markdown,
+        Section::Docs,
+        Section::Advanced,
+    ];
+
+    /// Path segment of the section's route, as in `/section/forms`.
+    pub fn slug(self) -> &'static str {
+        match self {
+            Section::Header => "top",
+            Section::Buttons => "buttons",
+            Section::Forms => "forms",
+            Section::Feedback => "feedback",
+            Section::Controls => "controls",
+            Section::Navigation => "navigation",
+            Section::Disclosure => "disclosure",
+            Section::DataDisplay => "data-display",
+            Section::Overlays => "overlays",
+            Section::Sheets => "sheets",
+            Section::Markdown => "markdown",
+            Section::Docs => "docs",
+            Section::Advanced => "advanced",
+        }
+    }
+
+    pub fn title_key(self) -> &'static str {
+        match self {
+            Section::Header => "sections.top",
+            Section::Buttons => "buttons.title",
+            Section::Forms => "forms.title",
+            Section::Feedback => "feedback.title",
+            Section::Controls => "controls.title",
+            Section::Navigation => "navigation.title",
+            Section::Disclosure => "disclosure.title",
+            Section::DataDisplay => "data_display.title",
+            Section::Overlays => "overlays.title",
+            Section::Sheets => "sheets.title",
+            Section::Markdown => "markdown.title",
+            Section::Docs => "docs.title",
+            Section::Advanced => "sections.advanced",
+        }
+    }
+
+    pub fn render(
+        self,
+        view: &mut ComponentGallery,
+        window: &mut Window,
+        cx: &mut Context<ComponentGallery>,
+    ) -> AnyElement {
+        match self {
+            Section::Header => header::render(view, window, cx),
+            Section::Buttons => buttons::render(view, window, cx),
+            Section::Forms => forms::render(view, window, cx),
+            Section::Feedback => feedback::render(view, window, cx),
+            Section::Controls => controls::render(view, window, cx),
+            Section::Navigation => navigation::render(view, window, cx),
+            Section::Disclosure => disclosure::render(view, window, cx),
+            Section::DataDisplay => data_display::render(view, window, cx),
+            Section::Overlays => overlays::render(view, window, cx),
+            Section::Sheets => sheets::render(view, window, cx),
+            Section::Markdown => markdown::render(view, window, cx),
+            Section::Docs => docs::render(view, window, cx),
+            Section::Advanced => advanced::render(view, window, cx),
+        }
+    }
+}
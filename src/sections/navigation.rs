@@ -1,58 +1,413 @@
 use gpui::prelude::FluentBuilder as _;
-use gpui::{px, AnyElement, IntoElement, ParentElement, Styled, Window};
+use gpui::{
+    div, px, AnyElement, App, Context, Entity, InteractiveElement as _, IntoElement, ParentElement,
+    Render, ScrollHandle, SharedString, StatefulInteractiveElement as _, Styled, Window,
+};
 use gpui_component::{
     breadcrumb::{Breadcrumb, BreadcrumbItem},
-    pagination::Pagination,
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::{Input, InputState},
     tab::{Tab, TabBar},
-    v_flex,
+    v_flex, ActiveTheme as _, IconName, Sizable,
 };
 
+use crate::focus::FocusTarget;
+use crate::history::Change;
+use crate::i18n::{t, t_args};
 use crate::ComponentGallery;
 
+/// What a tab shows. The built-in tabs can't be closed; added ones can.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TabKind {
+    Overview,
+    Details,
+    Stats,
+    /// A tab added with the "+" button, numbered from 1.
+    Custom(usize),
+}
+
+impl TabKind {
+    fn label(self, cx: &App) -> SharedString {
+        match self {
+            TabKind::Overview => t(cx, "navigation.overview"),
+            TabKind::Details => t(cx, "navigation.details"),
+            TabKind::Stats => t(cx, "navigation.stats"),
+            TabKind::Custom(n) => t_args(cx, "navigation.new_tab", &[("n", &n)]),
+        }
+    }
+
+    fn description_key(self) -> &'static str {
+        match self {
+            TabKind::Overview => "navigation.overview_body",
+            TabKind::Details => "navigation.details_body",
+            TabKind::Stats => "navigation.stats_body",
+            TabKind::Custom(_) => "navigation.custom_body",
+        }
+    }
+}
+
+/// A tab and the state of its panel, which lives as long as the tab does so
+/// switching away and back keeps what was typed or counted.
+pub struct TabPage {
+    id: usize,
+    kind: TabKind,
+    notes: Entity<InputState>,
+    clicks: usize,
+}
+
+/// The tab bar's pages, in display order.
+pub struct TabPages {
+    pages: Vec<TabPage>,
+    next_id: usize,
+    next_custom: usize,
+    scroll_handle: ScrollHandle,
+}
+
+impl TabPages {
+    pub fn new(window: &mut Window, cx: &mut App) -> Self {
+        let mut this = Self {
+            pages: Vec::new(),
+            next_id: 0,
+            next_custom: 1,
+            scroll_handle: ScrollHandle::new(),
+        };
+        for kind in [TabKind::Overview, TabKind::Details, TabKind::Stats] {
+            this.push(kind, window, cx);
+        }
+        this
+    }
+
+    pub fn len(&self) -> usize {
+        self.pages.len()
+    }
+
+    fn ids(&self) -> Vec<usize> {
+        self.pages.iter().map(|page| page.id).collect()
+    }
+
+    pub fn notes_inputs(&self) -> impl Iterator<Item = &Entity<InputState>> {
+        self.pages.iter().map(|page| &page.notes)
+    }
+
+    fn push(&mut self, kind: TabKind, window: &mut Window, cx: &mut App) {
+        let notes = cx.new(|cx| {
+            InputState::new(window, cx).placeholder(t(cx, "navigation.notes_placeholder"))
+        });
+        self.pages.push(TabPage {
+            id: self.next_id,
+            kind,
+            notes,
+            clicks: 0,
+        });
+        self.next_id += 1;
+    }
+}
+
+/// The tab to select after closing tab `closed` while `selected` was selected:
+/// the same tab, or the closed one's left neighbour if it was the selected one.
+fn selection_after_close(selected: usize, closed: usize) -> usize {
+    if closed < selected || (closed == selected && closed > 0) {
+        selected - 1
+    } else {
+        selected
+    }
+}
+
+/// Payload and drag preview for reordering tabs.
+#[derive(Clone)]
+struct DraggedTab {
+    ix: usize,
+    label: SharedString,
+}
+
+impl Render for DraggedTab {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .px_3()
+            .py_1()
+            .rounded(cx.theme().radius)
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().popover)
+            .shadow_md()
+            .text_sm()
+            .child(self.label.clone())
+    }
+}
+
+impl ComponentGallery {
+    /// Selects tab `ix`, clamped to the tabs that exist.
+    pub(crate) fn select_tab(&mut self, ix: usize, cx: &mut Context<Self>) {
+        self.tab_index = ix.min(self.tabs.len().saturating_sub(1));
+        self.tabs.scroll_handle.scroll_to_item(self.tab_index);
+        cx.notify();
+    }
+
+    fn add_tab(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let n = self.tabs.next_custom;
+        self.tabs.next_custom += 1;
+        self.tabs.push(TabKind::Custom(n), window, cx);
+        self.apply(Change::Tab(self.tabs.len() - 1), window, cx);
+    }
+
+    fn close_tab(&mut self, id: usize, cx: &mut Context<Self>) {
+        let Some(ix) = self.tabs.pages.iter().position(|page| page.id == id) else {
+            return;
+        };
+        let ids = self.tabs.ids();
+        self.tabs.pages.remove(ix);
+        self.remap_tab_history(&ids);
+        self.select_tab(selection_after_close(self.tab_index, ix), cx);
+    }
+
+    fn move_tab(&mut self, from: usize, to: usize, cx: &mut Context<Self>) {
+        if from == to || from >= self.tabs.len() || to >= self.tabs.len() {
+            return;
+        }
+        let selected = self.tabs.pages[self.tab_index].id;
+        let ids = self.tabs.ids();
+        let page = self.tabs.pages.remove(from);
+        self.tabs.pages.insert(to, page);
+        self.remap_tab_history(&ids);
+        let ix = self
+            .tabs
+            .pages
+            .iter()
+            .position(|page| page.id == selected)
+            .unwrap_or(0);
+        self.select_tab(ix, cx);
+    }
+
+    /// Points recorded tab changes at the same pages after the tab order
+    /// changed from `before`, so undo and redo select the tab they did before.
+    fn remap_tab_history(&mut self, before: &[usize]) {
+        let after = self.tabs.ids();
+        self.history.remap_tabs(|ix| {
+            let id = before.get(ix)?;
+            after.iter().position(|page| page == id)
+        });
+    }
+
+    fn render_tab_panel(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let page = &self.tabs.pages[self.tab_index];
+        let id = page.id;
+        v_flex()
+            .gap_2()
+            .p_3()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(cx.theme().radius)
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(t(cx, page.kind.description_key())),
+            )
+            .child(Input::new(&page.notes))
+            .child(
+                Button::new(("tab-clicks", id))
+                    .label(t_args(cx, "navigation.clicked", &[("n", &page.clicks)]))
+                    .outline()
+                    .small()
+                    .on_click(cx.listener(move |this, _, _, cx| {
+                        if let Some(page) = this.tabs.pages.iter_mut().find(|p| p.id == id) {
+                            page.clicks += 1;
+                        }
+                        cx.notify();
+                    })),
+            )
+    }
+}
+
+/// The current route's trail from `/`. Every item but the last opens its page.
+fn route_breadcrumb(view: &ComponentGallery, cx: &mut Context<ComponentGallery>) -> Breadcrumb {
+    let trail = view.router.current().trail();
+    let last = trail.len() - 1;
+    trail
+        .into_iter()
+        .enumerate()
+        .fold(Breadcrumb::new(), |breadcrumb, (ix, route)| {
+            let item = BreadcrumbItem::new(route.title(cx));
+            breadcrumb.child(if ix == last {
+                item
+            } else {
+                item.on_click(cx.listener(move |this, _, _, cx| this.navigate(route, cx)))
+            })
+        })
+}
+
+/// Back button and breadcrumb shown above every page but the home page.
+pub fn render_page_bar(
+    view: &mut ComponentGallery,
+    window: &mut Window,
+    cx: &mut gpui::Context<ComponentGallery>,
+) -> AnyElement {
+    h_flex()
+        .gap_2()
+        .items_center()
+        .child(
+            view.focus_ring(
+                FocusTarget::RouteBack,
+                Button::new("route-back")
+                    .icon(IconName::ArrowLeft)
+                    .ghost()
+                    .small()
+                    .tooltip(t(cx, "router.back"))
+                    .on_click(cx.listener(|this, _, _, cx| {
+                        this.pop_route(cx);
+                    })),
+                window,
+                cx,
+            ),
+        )
+        .child(route_breadcrumb(view, cx))
+        .into_any_element()
+}
+
 pub fn render(
     view: &mut ComponentGallery,
     window: &mut Window,
     cx: &mut gpui::Context<ComponentGallery>,
 ) -> AnyElement {
     let is_compact = window.bounds().size.width <= px(680.);
+
+    let tabs =
+        view.tabs
+            .pages
+            .iter()
+            .enumerate()
+            .map(|(ix, page)| {
+                let (id, label) = (page.id, page.kind.label(cx));
+                Tab::new()
+                    .label(label.clone())
+                    .when(matches!(page.kind, TabKind::Custom(_)), |this| {
+                        this.suffix(
+                            Button::new(("close-tab", id))
+                                .icon(IconName::Close)
+                                .ghost()
+                                .xsmall()
+                                .tooltip(t(cx, "navigation.close_tab"))
+                                .on_click(cx.listener(move |this, _, _, cx| {
+                                    cx.stop_propagation();
+                                    this.close_tab(id, cx)
+                                })),
+                        )
+                    })
+                    .on_drag(DraggedTab { ix, label }, |drag, _, _, cx| {
+                        cx.new(|_| drag.clone())
+                    })
+                    .drag_over::<DraggedTab>(|style, _, _, cx| style.bg(cx.theme().accent))
+                    .on_drop(cx.listener(move |this, drag: &DraggedTab, _, cx| {
+                        this.move_tab(drag.ix, ix, cx)
+                    }))
+            })
+            .collect::<Vec<_>>();
+
     view.card(
-        "Navigation",
+        "navigation.title",
         v_flex()
             .gap_3()
+            .child(route_breadcrumb(view, cx))
             .child(
-                Breadcrumb::new()
-                    .child("Home")
-                    .child("Components")
-                    .child(BreadcrumbItem::new("Gallery")),
+                view.focus_ring(
+                    FocusTarget::Tabs,
+                    TabBar::new("tabs")
+                        .track_scroll(&view.tabs.scroll_handle)
+                        .selected_index(view.tab_index)
+                        .when(is_compact, |this| this.w_full())
+                        .on_click(cx.listener(|this, ix, window, cx| {
+                            this.apply(Change::Tab(*ix), window, cx)
+                        }))
+                        .children(tabs)
+                        .suffix(
+                            Button::new("add-tab")
+                                .icon(IconName::Plus)
+                                .ghost()
+                                .small()
+                                .tooltip(t(cx, "navigation.add_tab"))
+                                .on_click(
+                                    cx.listener(|this, _, window, cx| this.add_tab(window, cx)),
+                                ),
+                        ),
+                    window,
+                    cx,
+                ),
             )
+            .child(view.render_tab_panel(cx))
             .child(
-                TabBar::new("tabs")
-                    .selected_index(view.tab_index)
-                    .when(is_compact, |this| this.w_full())
-                    .on_click(cx.listener(|this, ix, _, cx| {
-                        this.tab_index = *ix;
-                        cx.notify();
-                    }))
-                    .child(Tab::new().label("Overview"))
-                    .child(Tab::new().label("Details"))
-                    .child(Tab::new().label("Stats")),
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(t_args(
+                        cx,
+                        "navigation.route",
+                        &[("path", &view.router.current().path())],
+                    )),
             )
             .child(
-                Pagination::new("pagination")
-                    .current_page(view.pagination_page)
-                    .total_pages(8)
-                    .when(is_compact, |this| this.w_full())
-                    .on_click({
-                        let entity = cx.entity();
-                        move |page, _, cx| {
-                            entity.update(cx, |this, cx| {
-                                this.pagination_page = *page;
-                                cx.notify();
-                            });
-                        }
-                    }),
+                h_flex()
+                    .gap_2()
+                    .flex_wrap()
+                    .child(
+                        view.focus_ring(
+                            FocusTarget::OpenFormsPage,
+                            Button::new("open-forms-page")
+                                .label(t(cx, "navigation.open_forms"))
+                                .outline()
+                                .small()
+                                .on_click(cx.listener(|this, _, _, cx| {
+                                    this.navigate_to_path("/section/forms", cx)
+                                })),
+                            window,
+                            cx,
+                        ),
+                    )
+                    .child(
+                        view.focus_ring(
+                            FocusTarget::OpenTodo,
+                            Button::new("open-todo")
+                                .label(t(cx, "navigation.open_todo"))
+                                .outline()
+                                .small()
+                                .on_click(cx.listener(|this, _, _, cx| {
+                                    this.navigate_to_path("/demo/todo", cx)
+                                })),
+                            window,
+                            cx,
+                        ),
+                    ),
             ),
         cx,
     )
     .into_any_element()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closing_the_selected_tab_selects_its_left_neighbour() {
+        // Tab 3 is still to the right, but the left neighbour is selected.
+        assert_eq!(selection_after_close(2, 2), 1);
+        // The first tab has no left neighbour, so the next one moves into its place.
+        assert_eq!(selection_after_close(0, 0), 0);
+    }
+
+    #[test]
+    fn closing_another_tab_keeps_the_same_tab_selected() {
+        // A tab before it shifts the selected one left.
+        assert_eq!(selection_after_close(3, 1), 2);
+        // A tab after it leaves it in place.
+        assert_eq!(selection_after_close(1, 2), 1);
+    }
+
+    #[test]
+    fn closing_the_last_tab_while_selected_selects_the_new_last() {
+        // Four tabs, the last one selected and closed.
+        assert_eq!(selection_after_close(3, 3), 2);
+        // The last one closed while another is selected.
+        assert_eq!(selection_after_close(1, 3), 1);
+    }
+}
@@ -1,12 +1,25 @@
-use gpui::{px, AnyElement, IntoElement, Keystroke, ParentElement, Styled, Window};
+use gpui::{px, AnyElement, App, IntoElement, ParentElement, Styled, Window};
 use gpui_component::button::{Button, ButtonVariants};
 use gpui_component::{
-    h_flex, hover_card::HoverCard, kbd::Kbd, label::Label, link::Link, popover::Popover,
-    text::markdown, v_flex, ActiveTheme as _, Sizable,
+    h_flex, hover_card::HoverCard, kbd::Kbd, link::Link, notification::NotificationType,
+    popover::Popover, text::markdown, v_flex, ActiveTheme as _, Sizable,
 };
 
+use crate::clipboard;
+use crate::focus::FocusTarget;
+use crate::i18n::{t, t_args};
+use crate::keymap;
+use crate::notifications;
 use crate::ComponentGallery;
 
+const DOCS_URL: &str = "https://gpui.rs";
+
+fn copy_link(window: &mut Window, cx: &mut App) {
+    clipboard::copy(DOCS_URL, cx);
+    let message = t_args(cx, "forms.copied", &[("value", &DOCS_URL)]);
+    notifications::push(NotificationType::Success, message, window, cx);
+}
+
 pub fn render(
     view: &mut ComponentGallery,
     window: &mut Window,
@@ -18,33 +31,66 @@ pub fn render(
         v_flex()
             .gap_2()
             .child(
-                Button::new("tooltip")
-                    .label("Tooltip")
-                    .tooltip("This is a tooltip")
-                    .w_full(),
+                view.focus_ring(
+                    FocusTarget::Tooltip,
+                    Button::new("tooltip")
+                        .label(t(cx, "overlays.tooltip"))
+                        .tooltip(t(cx, "overlays.tooltip_text"))
+                        .w_full(),
+                    window,
+                    cx,
+                ),
             )
             .child(
-                Popover::new("popover")
-                    .trigger(Button::new("pop").label("Popover").outline().w_full())
-                    .content(|_, _, _| {
-                        v_flex()
-                            .gap_2()
-                            .p_3()
-                            .child("Quick actions")
-                            .child(Button::new("copy").label("Copy link").small())
-                    }),
+                view.focus_ring(
+                    FocusTarget::Popover,
+                    Popover::new("popover")
+                        .trigger(
+                            Button::new("pop")
+                                .label(t(cx, "overlays.popover"))
+                                .outline()
+                                .w_full(),
+                        )
+                        .content(|_, _, cx| {
+                            v_flex()
+                                .gap_2()
+                                .p_3()
+                                .child(t(cx, "overlays.quick_actions"))
+                                .child(
+                                    Button::new("copy")
+                                        .label(t(cx, "overlays.copy_link"))
+                                        .small()
+                                        .on_click(|_, window, cx| copy_link(window, cx)),
+                                )
+                        }),
+                    window,
+                    cx,
+                ),
             )
             .child(
-                HoverCard::new("hover")
-                    .trigger(Button::new("hover-trigger").label("Hover").ghost().w_full())
-                    .child(
-                        v_flex().gap_1().child("Hover card content").child(
-                            gpui::div()
-                                .text_sm()
-                                .text_color(cx.theme().muted_foreground)
-                                .child("Additional info"),
+                view.focus_ring(
+                    FocusTarget::HoverCard,
+                    HoverCard::new("hover")
+                        .trigger(
+                            Button::new("hover-trigger")
+                                .label(t(cx, "overlays.hover"))
+                                .ghost()
+                                .w_full(),
+                        )
+                        .child(
+                            v_flex()
+                                .gap_1()
+                                .child(t(cx, "overlays.hover_content"))
+                                .child(
+                                    gpui::div()
+                                        .text_sm()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(t(cx, "overlays.additional_info")),
+                                ),
                         ),
-                    ),
+                    window,
+                    cx,
+                ),
             )
             .into_any_element()
     } else {
@@ -52,32 +98,63 @@ pub fn render(
             .gap_3()
             .items_center()
             .child(
-                Button::new("tooltip")
-                    .label("Tooltip")
-                    .tooltip("This is a tooltip"),
+                view.focus_ring(
+                    FocusTarget::Tooltip,
+                    Button::new("tooltip")
+                        .label(t(cx, "overlays.tooltip"))
+                        .tooltip(t(cx, "overlays.tooltip_text")),
+                    window,
+                    cx,
+                ),
             )
             .child(
-                Popover::new("popover")
-                    .trigger(Button::new("pop").label("Popover").outline())
-                    .content(|_, _, _| {
-                        v_flex()
-                            .gap_2()
-                            .p_3()
-                            .child("Quick actions")
-                            .child(Button::new("copy").label("Copy link").small())
-                    }),
+                view.focus_ring(
+                    FocusTarget::Popover,
+                    Popover::new("popover")
+                        .trigger(
+                            Button::new("pop")
+                                .label(t(cx, "overlays.popover"))
+                                .outline(),
+                        )
+                        .content(|_, _, cx| {
+                            v_flex()
+                                .gap_2()
+                                .p_3()
+                                .child(t(cx, "overlays.quick_actions"))
+                                .child(
+                                    Button::new("copy")
+                                        .label(t(cx, "overlays.copy_link"))
+                                        .small()
+                                        .on_click(|_, window, cx| copy_link(window, cx)),
+                                )
+                        }),
+                    window,
+                    cx,
+                ),
             )
             .child(
-                HoverCard::new("hover")
-                    .trigger(Button::new("hover-trigger").label("Hover").ghost())
-                    .child(
-                        v_flex().gap_1().child("Hover card content").child(
-                            gpui::div()
-                                .text_sm()
-                                .text_color(cx.theme().muted_foreground)
-                                .child("Additional info"),
+                view.focus_ring(
+                    FocusTarget::HoverCard,
+                    HoverCard::new("hover")
+                        .trigger(
+                            Button::new("hover-trigger")
+                                .label(t(cx, "overlays.hover"))
+                                .ghost(),
+                        )
+                        .child(
+                            v_flex()
+                                .gap_1()
+                                .child(t(cx, "overlays.hover_content"))
+                                .child(
+                                    gpui::div()
+                                        .text_sm()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(t(cx, "overlays.additional_info")),
+                                ),
                         ),
-                    ),
+                    window,
+                    cx,
+                ),
             )
             .into_any_element()
     };
@@ -87,27 +164,65 @@ pub fn render(
             v_flex()
                 .gap_2()
                 .items_center()
-                .child(Kbd::new(Keystroke::parse("cmd-k").unwrap()))
-                .child(Label::new("Command Palette"))
-                .child(Link::new("link").href("https://gpui.rs").child("Docs"))
+                .children(keymap::keystroke_for("ToggleCommandPalette", cx).map(Kbd::new))
+                .child(
+                    view.focus_ring(
+                        FocusTarget::CommandPalette,
+                        Button::new("command-palette")
+                            .label(t(cx, "overlays.command_palette"))
+                            .ghost()
+                            .small()
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.open_command_palette(window, cx)
+                            })),
+                        window,
+                        cx,
+                    ),
+                )
+                .child(
+                    view.focus_ring(
+                        FocusTarget::DocsLink,
+                        Link::new("link")
+                            .href(DOCS_URL)
+                            .child(t(cx, "overlays.docs")),
+                        window,
+                        cx,
+                    ),
+                )
                 .into_any_element()
         } else {
             h_flex()
                 .gap_2()
                 .items_center()
-                .child(Kbd::new(Keystroke::parse("cmd-k").unwrap()))
-                .child(Label::new("Command Palette"))
-                .child(Link::new("link").href("https://gpui.rs").child("Docs"))
+                .children(keymap::keystroke_for("ToggleCommandPalette", cx).map(Kbd::new))
+                .child(
+                    view.focus_ring(
+                        FocusTarget::CommandPalette,
+                        Button::new("command-palette")
+                            .label(t(cx, "overlays.command_palette"))
+                            .ghost()
+                            .small()
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.open_command_palette(window, cx)
+                            })),
+                        window,
+                        cx,
+                    ),
+                )
+                .child(
+                    view.focus_ring(
+                        FocusTarget::DocsLink,
+                        Link::new("link")
+                            .href(DOCS_URL)
+                            .child(t(cx, "overlays.docs")),
+                        window,
+                        cx,
+                    ),
+                )
                 .into_any_element()
         })
-        .child(
-            markdown(
-                "**Markdown** demo:\n\n- 支持列表\n- 支持 _强调_\n- 支持 `inline code`\n\n> 已重新开启 markdown 渲染，用于验证当前修复是否生效。",
-            )
-            .w_full(),
-        );
+        .child(markdown(t(cx, "overlays.markdown")).w_full());
 
     let _ = view;
-    view.card("Overlay & Helpers", content, cx)
-        .into_any_element()
+    view.card("overlays.title", content, cx).into_any_element()
 }
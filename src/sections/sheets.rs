@@ -0,0 +1,121 @@
+use gpui::prelude::FluentBuilder as _;
+use gpui::{div, px, AnyElement, Context, IntoElement, ParentElement, Styled, Window};
+use gpui_component::{
+    button::{Button, ButtonVariants},
+    h_flex, v_flex, ActiveTheme as _, Placement,
+};
+
+use crate::focus::FocusTarget;
+use crate::i18n::{t, t_args};
+use crate::ComponentGallery;
+
+/// Sheet body for stack level `depth`, with a button that opens the next level.
+fn nested_content(
+    depth: usize,
+) -> impl Fn(&mut Window, &mut Context<ComponentGallery>) -> AnyElement + 'static {
+    move |_, cx| {
+        v_flex()
+            .gap_3()
+            .child(t_args(cx, "sheets.level_body", &[("n", &depth)]))
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(t(cx, "sheets.nested_hint")),
+            )
+            .child(
+                Button::new(("open-nested", depth))
+                    .label(t(cx, "sheets.open_nested"))
+                    .outline()
+                    .on_click(cx.listener(move |this, _, window, cx| {
+                        let title = t_args(cx, "sheets.level", &[("n", &(depth + 1))]);
+                        this.push_sheet(
+                            title,
+                            Placement::Right,
+                            nested_content(depth + 1),
+                            window,
+                            cx,
+                        )
+                    })),
+            )
+            .into_any_element()
+    }
+}
+
+impl ComponentGallery {
+    fn open_demo_sheet(
+        &mut self,
+        placement: Placement,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let title = t_args(cx, "sheets.level", &[("n", &1)]);
+        self.push_sheet(title, placement, nested_content(1), window, cx);
+    }
+
+    pub(crate) fn activate_sheet_target(
+        &mut self,
+        target: FocusTarget,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let placement = match target {
+            FocusTarget::SheetLeft => Placement::Left,
+            FocusTarget::SheetRight => Placement::Right,
+            FocusTarget::SheetTop => Placement::Top,
+            FocusTarget::SheetBottom => Placement::Bottom,
+            _ => return,
+        };
+        self.open_demo_sheet(placement, window, cx);
+    }
+}
+
+pub fn render(
+    view: &mut ComponentGallery,
+    window: &mut Window,
+    cx: &mut gpui::Context<ComponentGallery>,
+) -> AnyElement {
+    let is_compact = window.bounds().size.width <= px(680.);
+    let buttons = [
+        (FocusTarget::SheetLeft, "sheets.left"),
+        (FocusTarget::SheetRight, "sheets.right"),
+        (FocusTarget::SheetTop, "sheets.top"),
+        (FocusTarget::SheetBottom, "sheets.bottom"),
+    ];
+
+    view.card(
+        "sheets.title",
+        v_flex()
+            .gap_3()
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(t(cx, "sheets.description")),
+            )
+            .child(
+                if is_compact {
+                    v_flex().gap_2()
+                } else {
+                    h_flex().gap_2()
+                }
+                .children(buttons.into_iter().map(|(target, label)| {
+                    view.focus_ring(
+                        target,
+                        Button::new(label)
+                            .label(t(cx, label))
+                            .outline()
+                            .when(is_compact, |this| this.w_full())
+                            .on_click(cx.listener(move |this, _, window, cx| {
+                                this.activate_sheet_target(target, window, cx)
+                            })),
+                        window,
+                        cx,
+                    )
+                    .when(is_compact, |this| this.w_full())
+                })),
+            ),
+        cx,
+    )
+    .into_any_element()
+}
@@ -0,0 +1,326 @@
+use std::rc::{Rc, Weak};
+
+use gpui::{
+    div, prelude::*, px, AnyElement, App, Context, DragMoveEvent, Empty, MouseButton, Pixels,
+    SharedString, Size, WeakEntity, Window,
+};
+use gpui_component::{
+    button::{Button, ButtonVariants},
+    h_flex, v_flex, ActiveTheme as _, IconName, Placement, Sizable, StyledExt as _, WindowExt as _,
+};
+
+use crate::i18n::t;
+use crate::ComponentGallery;
+
+/// Heights a bottom sheet settles at, as a fraction of the window height.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Snap {
+    Peek,
+    Half,
+    Full,
+}
+
+impl Snap {
+    const ALL: [Snap; 3] = [Snap::Peek, Snap::Half, Snap::Full];
+
+    fn fraction(self) -> f32 {
+        match self {
+            Snap::Peek => 0.25,
+            Snap::Half => 0.5,
+            Snap::Full => 0.92,
+        }
+    }
+
+    /// The snap point closest to `fraction`, or `None` if the sheet was dragged
+    /// below [`DISMISS_FRACTION`] to dismiss it.
+    fn nearest(fraction: f32) -> Option<Snap> {
+        if fraction < DISMISS_FRACTION {
+            return None;
+        }
+        Snap::ALL.into_iter().min_by(|a, b| {
+            let da = (a.fraction() - fraction).abs();
+            let db = (b.fraction() - fraction).abs();
+            da.total_cmp(&db)
+        })
+    }
+}
+
+/// A bottom sheet released below this fraction of the window is dismissed.
+const DISMISS_FRACTION: f32 = 0.15;
+
+/// Side panels take this share of the window, capped at [`SIDE_MAX_WIDTH`].
+const SIDE_FRACTION: f32 = 0.8;
+const SIDE_MAX_WIDTH: f32 = 360.;
+const TOP_FRACTION: f32 = 0.4;
+
+/// The height, as a fraction of the window, of a bottom sheet whose handle
+/// is at `pointer_y`. It never grows past [`Snap::Full`].
+fn dragged_fraction(pointer_y: f32, window_height: f32) -> f32 {
+    ((window_height - pointer_y) / window_height).clamp(0., Snap::Full.fraction())
+}
+
+type SheetContent = Rc<dyn Fn(&mut Window, &mut Context<ComponentGallery>) -> AnyElement>;
+
+struct SheetEntry {
+    title: SharedString,
+    placement: Placement,
+    snap: Snap,
+    content: SheetContent,
+}
+
+/// Payload of a bottom sheet's handle drag.
+#[derive(Clone, Copy)]
+struct SheetDrag;
+
+impl Render for SheetDrag {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
+/// Sheets opened on top of each other in Root's sheet layer. Root shows one
+/// sheet at a time, so only the topmost is open; Back reopens the one below.
+///
+/// This is an entity, rendered as the open sheet's child, because Root builds
+/// its sheet while the gallery is rendering and the gallery can't be updated then.
+pub struct SheetStack {
+    gallery: WeakEntity<ComponentGallery>,
+    entries: Vec<SheetEntry>,
+    /// Height of the bottom sheet being dragged, as a fraction of the window.
+    drag: Option<f32>,
+    /// Held by the open sheet's builder. Root drops the builder when its
+    /// sheet is closed or replaced by another, which ends the stack.
+    shown: Weak<()>,
+}
+
+impl SheetStack {
+    pub fn new(gallery: WeakEntity<ComponentGallery>) -> Self {
+        Self {
+            gallery,
+            entries: Vec::new(),
+            drag: None,
+            shown: Weak::new(),
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        if self.shown.strong_count() == 0 {
+            0
+        } else {
+            self.entries.len()
+        }
+    }
+
+    fn push(&mut self, entry: SheetEntry, window: &mut Window, cx: &mut Context<Self>) {
+        if self.depth() == 0 {
+            self.entries.clear();
+        }
+        self.entries.push(entry);
+        self.show_top(window, cx);
+    }
+
+    fn pop(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.entries.pop();
+        self.show_top(window, cx);
+    }
+
+    /// Opens the topmost entry in Root's sheet layer, or closes the layer
+    /// when the stack is empty.
+    fn show_top(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.drag = None;
+        cx.notify();
+        if self.entries.is_empty() {
+            self.shown = Weak::new();
+            window.close_sheet(cx);
+            return;
+        }
+
+        let token = Rc::new(());
+        self.shown = Rc::downgrade(&token);
+        let stack = cx.entity();
+        window.open_sheet(cx, move |sheet, window, cx| {
+            let _shown = &token;
+            let Some((placement, size)) = stack.read(cx).size(window.viewport_size()) else {
+                return sheet;
+            };
+            sheet.placement(placement).size(size).child(stack.clone())
+        });
+    }
+
+    /// Placement and size of the topmost sheet.
+    fn size(&self, viewport: Size<Pixels>) -> Option<(Placement, Pixels)> {
+        let entry = self.entries.last()?;
+        let size = match entry.placement {
+            Placement::Left | Placement::Right => {
+                px((f32::from(viewport.width) * SIDE_FRACTION).min(SIDE_MAX_WIDTH))
+            }
+            Placement::Top => viewport.height * TOP_FRACTION,
+            Placement::Bottom => viewport.height * self.drag.unwrap_or(entry.snap.fraction()),
+        };
+        Some((entry.placement, size))
+    }
+
+    fn update_drag(&mut self, pointer_y: Pixels, viewport: Size<Pixels>, cx: &mut Context<Self>) {
+        self.drag = Some(dragged_fraction(
+            f32::from(pointer_y),
+            f32::from(viewport.height),
+        ));
+        cx.notify();
+    }
+
+    /// Settles a dragged bottom sheet at the nearest snap point, or dismisses it.
+    fn finish_drag(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(fraction) = self.drag.take() else {
+            return;
+        };
+        match Snap::nearest(fraction) {
+            Some(snap) => {
+                if let Some(entry) = self.entries.last_mut() {
+                    entry.snap = snap;
+                }
+                cx.notify();
+            }
+            None => self.pop(window, cx),
+        }
+    }
+
+    /// The grab handle on top of a bottom sheet. Dragging it resizes the sheet.
+    fn render_handle(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .id("sheet-handle")
+            .w_full()
+            .h(px(24.))
+            .flex()
+            .items_center()
+            .justify_center()
+            .cursor_grab()
+            .on_drag(SheetDrag, |drag, _, _, cx| cx.new(|_| *drag))
+            .on_drag_move(
+                cx.listener(|this, ev: &DragMoveEvent<SheetDrag>, window, cx| {
+                    this.update_drag(ev.event.position.y, window.viewport_size(), cx)
+                }),
+            )
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(|this, _, window, cx| this.finish_drag(window, cx)),
+            )
+            .on_mouse_up_out(
+                MouseButton::Left,
+                cx.listener(|this, _, window, cx| this.finish_drag(window, cx)),
+            )
+            .child(
+                div()
+                    .w(px(36.))
+                    .h(px(4.))
+                    .rounded_full()
+                    .bg(cx.theme().border),
+            )
+    }
+}
+
+impl Render for SheetStack {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let depth = self.entries.len();
+        let Some(entry) = self.entries.last() else {
+            return Empty.into_any_element();
+        };
+        let (title, placement, content) =
+            (entry.title.clone(), entry.placement, entry.content.clone());
+        let body = self
+            .gallery
+            .update(cx, |_, cx| content(window, cx))
+            .unwrap_or_else(|_| Empty.into_any_element());
+
+        v_flex()
+            .size_full()
+            .gap_3()
+            .when(placement == Placement::Bottom, |this| {
+                this.child(self.render_handle(cx))
+            })
+            .child(
+                h_flex()
+                    .gap_2()
+                    .items_center()
+                    .when(depth > 1, |this| {
+                        this.child(
+                            Button::new(("sheet-back", depth))
+                                .icon(IconName::ArrowLeft)
+                                .ghost()
+                                .small()
+                                .tooltip(t(cx, "sheets.back"))
+                                .on_click(cx.listener(|this, _, window, cx| this.pop(window, cx))),
+                        )
+                    })
+                    .child(div().flex_1().font_semibold().child(title)),
+            )
+            .child(
+                div()
+                    .id("sheet-body")
+                    .flex_1()
+                    .overflow_y_scroll()
+                    .child(body),
+            )
+            .into_any_element()
+    }
+}
+
+impl ComponentGallery {
+    pub(crate) fn push_sheet(
+        &mut self,
+        title: impl Into<SharedString>,
+        placement: Placement,
+        content: impl Fn(&mut Window, &mut Context<Self>) -> AnyElement + 'static,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let entry = SheetEntry {
+            title: title.into(),
+            placement,
+            snap: Snap::Half,
+            content: Rc::new(content),
+        };
+        self.sheet_stack
+            .update(cx, |stack, cx| stack.push(entry, window, cx));
+    }
+
+    pub(crate) fn pop_sheet(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.sheet_stack
+            .update(cx, |stack, cx| stack.pop(window, cx));
+    }
+
+    /// How many stacked sheets are open, or 0 if another sheet replaced them.
+    pub(crate) fn sheet_depth(&self, cx: &App) -> usize {
+        self.sheet_stack.read(cx).depth()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn releasing_snaps_to_the_closest_height() {
+        assert_eq!(Snap::nearest(0.2), Some(Snap::Peek));
+        assert_eq!(Snap::nearest(0.37), Some(Snap::Peek));
+        assert_eq!(Snap::nearest(0.38), Some(Snap::Half));
+        assert_eq!(Snap::nearest(0.7), Some(Snap::Half));
+        assert_eq!(Snap::nearest(0.72), Some(Snap::Full));
+        assert_eq!(Snap::nearest(1.), Some(Snap::Full));
+    }
+
+    #[test]
+    fn releasing_below_the_dismiss_threshold_closes_the_sheet() {
+        assert_eq!(Snap::nearest(DISMISS_FRACTION), Some(Snap::Peek));
+        assert_eq!(Snap::nearest(DISMISS_FRACTION - 0.01), None);
+        assert_eq!(Snap::nearest(0.), None);
+    }
+
+    #[test]
+    fn dragging_follows_the_pointer_up_to_full_height() {
+        assert_eq!(dragged_fraction(750., 1000.), 0.25);
+        assert_eq!(dragged_fraction(900., 1000.), 0.1);
+        assert!(Snap::nearest(dragged_fraction(900., 1000.)).is_none());
+        assert_eq!(dragged_fraction(0., 1000.), Snap::Full.fraction());
+        assert_eq!(dragged_fraction(1200., 1000.), 0.);
+    }
+}
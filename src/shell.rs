@@ -0,0 +1,273 @@
+use gpui::{
+    prelude::*, AnyElement, Context, Empty, SharedString, Subscription, WeakEntity, Window,
+};
+use gpui_component::{
+    sidebar::{
+        Sidebar, SidebarFooter, SidebarHeader, SidebarMenu, SidebarMenuItem, SidebarToggleButton,
+    },
+    IconName, Placement, Side, WindowExt as _,
+};
+
+use crate::i18n::t;
+use crate::keymap::ToggleSidebar;
+use crate::router::{Demo, Route};
+use crate::sections::Section;
+use crate::ComponentGallery;
+
+/// Expandable groups of the navigation sidebar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NavGroup {
+    Components,
+    Demos,
+}
+
+impl NavGroup {
+    pub const ALL: [NavGroup; 2] = [NavGroup::Components, NavGroup::Demos];
+
+    /// Name the group is persisted under.
+    pub fn slug(self) -> &'static str {
+        match self {
+            NavGroup::Components => "components",
+            NavGroup::Demos => "demos",
+        }
+    }
+
+    fn label_key(self) -> &'static str {
+        match self {
+            NavGroup::Components => "sidebar.components",
+            NavGroup::Demos => "sidebar.demos",
+        }
+    }
+
+    fn icon(self) -> IconName {
+        match self {
+            NavGroup::Components => IconName::Frame,
+            NavGroup::Demos => IconName::SquareTerminal,
+        }
+    }
+
+    fn routes(self) -> Vec<Route> {
+        match self {
+            NavGroup::Components => Section::ALL
+                .into_iter()
+                .filter(|section| *section != Section::Header)
+                .map(Route::Section)
+                .collect(),
+            NavGroup::Demos => Demo::ALL.into_iter().map(Route::Demo).collect(),
+        }
+    }
+}
+
+/// Every sidebar destination, top to bottom, including those in closed groups.
+fn nav_routes() -> Vec<Route> {
+    let mut routes = vec![Route::Home];
+    routes.extend(NavGroup::ALL.into_iter().flat_map(NavGroup::routes));
+    routes.push(Route::Settings);
+    routes
+}
+
+impl ComponentGallery {
+    pub(crate) fn is_nav_group_open(&self, group: NavGroup) -> bool {
+        self.sidebar_groups.iter().any(|slug| slug == group.slug())
+    }
+
+    /// A number that changes whenever a group opens or closes.
+    fn nav_groups_key(&self) -> usize {
+        NavGroup::ALL
+            .into_iter()
+            .enumerate()
+            .filter(|(_, group)| self.is_nav_group_open(*group))
+            .fold(0, |key, (ix, _)| key | 1 << ix)
+    }
+
+    fn toggle_nav_group(&mut self, group: NavGroup, cx: &mut Context<Self>) {
+        if self.is_nav_group_open(group) {
+            self.sidebar_groups.retain(|slug| slug != group.slug());
+        } else {
+            self.sidebar_groups.push(group.slug().into());
+        }
+        cx.notify();
+    }
+
+    /// Goes to the sidebar item `delta` places from the current route,
+    /// opening its group so the active item stays visible.
+    pub(crate) fn step_nav(&mut self, delta: isize, cx: &mut Context<Self>) {
+        let routes = nav_routes();
+        let current = self.router.current();
+        let ix = routes
+            .iter()
+            .position(|route| *route == current)
+            .unwrap_or(0);
+        let ix = (ix as isize + delta).clamp(0, routes.len() as isize - 1) as usize;
+        let route = routes[ix];
+        if let Some(group) = NavGroup::ALL
+            .into_iter()
+            .find(|group| group.routes().contains(&route))
+        {
+            if !self.is_nav_group_open(group) {
+                self.sidebar_groups.push(group.slug().into());
+            }
+        }
+        self.navigate(route, cx);
+        cx.notify();
+    }
+
+    /// Opens the sidebar as a drawer, for widths too narrow to keep it beside the content.
+    pub(crate) fn open_nav_drawer(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let gallery = cx.entity();
+        let drawer = cx.new(|cx| NavDrawer {
+            gallery: gallery.downgrade(),
+            _observe: cx.observe(&gallery, |_, _, cx| cx.notify()),
+        });
+        let placement = if self.sidebar_side_right {
+            Placement::Right
+        } else {
+            Placement::Left
+        };
+        window.defer(cx, move |window, cx| {
+            window.open_sheet(cx, move |sheet, _, _| {
+                sheet.placement(placement).child(drawer.clone())
+            });
+        });
+    }
+}
+
+/// The sidebar inside the nav drawer. Root builds its sheet while the
+/// gallery is rendering, so the sidebar is drawn from this view instead,
+/// once the gallery can be updated.
+struct NavDrawer {
+    gallery: WeakEntity<ComponentGallery>,
+    _observe: Subscription,
+}
+
+impl Render for NavDrawer {
+    fn render(&mut self, _: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.gallery
+            .update(cx, |gallery, cx| render_sidebar(gallery, true, cx))
+            .unwrap_or_else(|_| Empty.into_any_element())
+    }
+}
+
+/// The app's navigation. The active item is the current route, and clicking
+/// an item navigates to it. In the drawer it is never collapsed, and picking
+/// an item closes the drawer.
+pub fn render_sidebar(
+    view: &mut ComponentGallery,
+    in_drawer: bool,
+    cx: &mut Context<ComponentGallery>,
+) -> AnyElement {
+    let current = view.router.current();
+    let side = if view.sidebar_side_right {
+        Side::Right
+    } else {
+        Side::Left
+    };
+    let collapsed = view.sidebar_collapsed && !in_drawer;
+
+    let item = |route: Route, label: SharedString, cx: &mut Context<ComponentGallery>| {
+        SidebarMenuItem::new(label)
+            .active(current == route)
+            .on_click(cx.listener(move |this, _, window, cx| {
+                this.navigate(route, cx);
+                if in_drawer {
+                    window.close_sheet(cx);
+                }
+            }))
+    };
+
+    let mut menu = SidebarMenu::new()
+        .child(item(Route::Home, t(cx, "sidebar.overview"), cx).icon(IconName::LayoutDashboard));
+    for group in NavGroup::ALL {
+        let children = group
+            .routes()
+            .into_iter()
+            .map(|route| item(route, route.title(cx), cx))
+            .collect::<Vec<_>>();
+        // The item only reads `default_open` when its state is first created,
+        // and the sidebar's id below changes with `sidebar_groups`, so the
+        // state is recreated from ours whenever a group opens or closes.
+        menu = menu.child(
+            SidebarMenuItem::new(t(cx, group.label_key()))
+                .icon(group.icon())
+                .default_open(view.is_nav_group_open(group))
+                .on_click(cx.listener(move |this, _, _, cx| this.toggle_nav_group(group, cx)))
+                .children(children),
+        );
+    }
+    menu =
+        menu.child(item(Route::Settings, t(cx, "sidebar.settings"), cx).icon(IconName::Settings));
+
+    Sidebar::new((
+        if in_drawer {
+            "nav-drawer"
+        } else {
+            "nav-sidebar"
+        },
+        view.nav_groups_key(),
+    ))
+    .collapsed(collapsed)
+    .side(side)
+    .header(
+        SidebarHeader::new()
+            .child(t(cx, "sidebar.workspace"))
+            .text_sm(),
+    )
+    .child(menu)
+    .when(!in_drawer, |this| {
+        this.footer(
+            SidebarFooter::new()
+                .child(concat!("v", env!("CARGO_PKG_VERSION")))
+                .child(
+                    SidebarToggleButton::new()
+                        .side(side)
+                        .collapsed(collapsed)
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.toggle_sidebar(&ToggleSidebar, window, cx)
+                        })),
+                ),
+        )
+    })
+    .into_any_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::*;
+    use crate::test_support::open_gallery;
+
+    #[test]
+    fn nav_routes_list_every_page_once() {
+        let routes = nav_routes();
+        assert_eq!(routes.first(), Some(&Route::Home));
+        assert_eq!(routes.last(), Some(&Route::Settings));
+        for (ix, route) in routes.iter().enumerate() {
+            assert!(!routes[ix + 1..].contains(route), "{route:?}");
+        }
+    }
+
+    #[gpui::test]
+    fn arrow_keys_walk_the_sidebar_and_open_the_group_they_enter(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        gallery.update_in(cx, |this, _, cx| {
+            this.sidebar_groups = vec![NavGroup::Components.slug().into()];
+            let key = this.nav_groups_key();
+
+            this.step_nav(-1, cx);
+            assert_eq!(this.router.current(), Route::Home);
+
+            this.step_nav(1, cx);
+            assert_eq!(this.router.current(), Route::Section(Section::Buttons));
+
+            let last_section = NavGroup::Components.routes().len() as isize;
+            this.step_nav(last_section, cx);
+            assert_eq!(this.router.current(), Route::Demo(Demo::Todo));
+            assert!(this.is_nav_group_open(NavGroup::Demos));
+            assert_ne!(this.nav_groups_key(), key);
+
+            this.step_nav(isize::MAX / 2, cx);
+            assert_eq!(this.router.current(), Route::Settings);
+        });
+    }
+}
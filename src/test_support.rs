@@ -0,0 +1,25 @@
+use gpui::{AppContext as _, Entity, TestAppContext, VisualTestContext};
+use gpui_component::Root;
+
+use crate::{clipboard, i18n, keymap, notifications, persistence, ComponentGallery};
+
+/// Opens the gallery in a test window inside a `Root`, set up as the app
+/// does but with an in-memory clipboard and no state file.
+pub fn open_gallery(cx: &mut TestAppContext) -> (Entity<ComponentGallery>, &mut VisualTestContext) {
+    cx.update(|cx| {
+        gpui_component::init(cx);
+        i18n::init(cx);
+        keymap::init(cx);
+        notifications::init(cx);
+        clipboard::init(clipboard::MemoryClipboard::default(), cx);
+        persistence::init(None, cx);
+    });
+
+    let mut gallery = None;
+    let (_, cx) = cx.add_window_view(|window, cx| {
+        let view = cx.new(|cx| ComponentGallery::new(window, cx));
+        gallery = Some(view.clone());
+        Root::new(view, window, cx)
+    });
+    (gallery.expect("the window builds the gallery"), cx)
+}
@@ -0,0 +1,176 @@
+use gpui::{div, prelude::*, AnyElement, App, AppContext as _, Context, Entity, Window};
+use gpui_component::{
+    button::{Button, ButtonVariants},
+    checkbox::Checkbox,
+    h_flex,
+    input::{Input, InputState},
+    v_flex, ActiveTheme as _, IconName, Sizable,
+};
+
+use crate::i18n::{t, t_args};
+use crate::ComponentGallery;
+
+enum TodoText {
+    /// Message key of a sample item, so it follows the language.
+    Sample(&'static str),
+    Typed(String),
+}
+
+struct TodoItem {
+    id: usize,
+    text: TodoText,
+    done: bool,
+}
+
+/// State of the `/demo/todo` page. It lives on the gallery, so leaving the
+/// page and coming back keeps the list.
+pub struct TodoList {
+    pub input: Entity<InputState>,
+    items: Vec<TodoItem>,
+    next_id: usize,
+}
+
+impl TodoList {
+    pub fn new(window: &mut Window, cx: &mut App) -> Self {
+        let mut this = Self {
+            input: cx.new(|cx| InputState::new(window, cx).placeholder(t(cx, "todo.placeholder"))),
+            items: Vec::new(),
+            next_id: 0,
+        };
+        for key in ["todo.sample_route", "todo.sample_back"] {
+            this.push(TodoText::Sample(key));
+        }
+        this
+    }
+
+    fn push(&mut self, text: TodoText) {
+        self.items.push(TodoItem {
+            id: self.next_id,
+            text,
+            done: false,
+        });
+        self.next_id += 1;
+    }
+
+    fn remaining(&self) -> usize {
+        self.items.iter().filter(|item| !item.done).count()
+    }
+}
+
+impl ComponentGallery {
+    /// Adds the input's text as a new item and clears the input.
+    pub(crate) fn add_todo(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let text = self.todo.input.read(cx).value().trim().to_string();
+        if text.is_empty() {
+            return;
+        }
+        self.todo.push(TodoText::Typed(text));
+        self.todo
+            .input
+            .update(cx, |state, cx| state.set_value("", window, cx));
+        cx.notify();
+    }
+
+    fn toggle_todo(&mut self, id: usize, cx: &mut Context<Self>) {
+        if let Some(item) = self.todo.items.iter_mut().find(|item| item.id == id) {
+            item.done = !item.done;
+        }
+        cx.notify();
+    }
+
+    fn remove_todo(&mut self, id: usize, cx: &mut Context<Self>) {
+        self.todo.items.retain(|item| item.id != id);
+        cx.notify();
+    }
+
+    fn clear_done_todos(&mut self, cx: &mut Context<Self>) {
+        self.todo.items.retain(|item| !item.done);
+        cx.notify();
+    }
+}
+
+pub fn render(
+    view: &mut ComponentGallery,
+    _window: &mut Window,
+    cx: &mut Context<ComponentGallery>,
+) -> AnyElement {
+    let remaining = view.todo.remaining();
+    let has_done = remaining < view.todo.items.len();
+
+    let items = view
+        .todo
+        .items
+        .iter()
+        .map(|item| {
+            let id = item.id;
+            let text = match &item.text {
+                TodoText::Sample(key) => t(cx, key),
+                TodoText::Typed(text) => text.clone().into(),
+            };
+            h_flex()
+                .gap_2()
+                .py_1()
+                .justify_between()
+                .border_b_1()
+                .border_color(cx.theme().border)
+                .child(
+                    Checkbox::new(("todo", id))
+                        .label(text)
+                        .checked(item.done)
+                        .on_click(cx.listener(move |this, _, _, cx| this.toggle_todo(id, cx))),
+                )
+                .child(
+                    Button::new(("remove-todo", id))
+                        .icon(IconName::Close)
+                        .ghost()
+                        .xsmall()
+                        .tooltip(t(cx, "todo.remove"))
+                        .on_click(cx.listener(move |this, _, _, cx| this.remove_todo(id, cx))),
+                )
+        })
+        .collect::<Vec<_>>();
+
+    view.card(
+        "todo.title",
+        v_flex()
+            .gap_3()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(div().flex_1().child(Input::new(&view.todo.input)))
+                    .child(
+                        Button::new("add-todo")
+                            .label(t(cx, "todo.add"))
+                            .primary()
+                            .small()
+                            .on_click(cx.listener(|this, _, window, cx| this.add_todo(window, cx))),
+                    ),
+            )
+            .when(items.is_empty(), |this| {
+                this.child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(t(cx, "todo.empty")),
+                )
+            })
+            .children(items)
+            .child(
+                h_flex()
+                    .justify_between()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(t_args(cx, "todo.remaining", &[("n", &remaining)]))
+                    .child(
+                        Button::new("clear-done-todos")
+                            .label(t(cx, "todo.clear_done"))
+                            .ghost()
+                            .xsmall()
+                            .disabled(!has_done)
+                            .on_click(cx.listener(|this, _, _, cx| this.clear_done_todos(cx))),
+                    ),
+            ),
+        cx,
+    )
+    .into_any_element()
+}
@@ -0,0 +1,159 @@
+use gpui::{App, AppContext as _, Entity, Window};
+use gpui_component::input::InputState;
+
+use crate::history::Change;
+use crate::i18n::t;
+use crate::ComponentGallery;
+
+/// Steps of the sign-in wizard, matching the Stepper's items.
+pub const STEP_LOGIN: usize = 0;
+pub const STEP_VERIFY: usize = 1;
+pub const STEP_DONE: usize = 2;
+
+const MIN_USERNAME: usize = 3;
+const MIN_PASSWORD: usize = 6;
+const CODE_LENGTH: usize = 6;
+
+/// Form state for the Stepper wizard. The inputs outlive the step that shows
+/// them, so going Back finds everything as it was left.
+pub struct Wizard {
+    pub username: Entity<InputState>,
+    pub password: Entity<InputState>,
+    pub code: Entity<InputState>,
+    /// How many steps have passed validation. The Stepper only jumps to
+    /// steps up to this one.
+    pub completed: usize,
+    /// Message key of the current step's validation error.
+    pub error: Option<&'static str>,
+}
+
+impl Wizard {
+    pub fn new(window: &mut Window, cx: &mut App) -> Self {
+        Self {
+            username: cx
+                .new(|cx| InputState::new(window, cx).placeholder(t(cx, "wizard.username"))),
+            password: cx.new(|cx| {
+                InputState::new(window, cx)
+                    .masked(true)
+                    .placeholder(t(cx, "wizard.password"))
+            }),
+            code: cx.new(|cx| InputState::new(window, cx).placeholder("123456")),
+            completed: STEP_LOGIN,
+            error: None,
+        }
+    }
+
+    /// Checks the fields of `step`, returning the message key of the first problem.
+    fn validate(&self, step: usize, cx: &App) -> Result<(), &'static str> {
+        match step {
+            STEP_LOGIN => {
+                if self.username.read(cx).value().trim().chars().count() < MIN_USERNAME {
+                    return Err("wizard.username_short");
+                }
+                if self.password.read(cx).value().chars().count() < MIN_PASSWORD {
+                    return Err("wizard.password_short");
+                }
+                Ok(())
+            }
+            STEP_VERIFY => {
+                let code = self.code.read(cx).value();
+                let code = code.trim();
+                if code.len() != CODE_LENGTH || !code.chars().all(|c| c.is_ascii_digit()) {
+                    return Err("wizard.code_invalid");
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl ComponentGallery {
+    /// Whether the Stepper may jump to `step`: the current step, or one
+    /// already completed.
+    pub(crate) fn can_visit_step(&self, step: usize) -> bool {
+        step <= self.wizard.completed.max(self.stepper_step)
+    }
+
+    pub(crate) fn wizard_next(&mut self, window: &mut Window, cx: &mut gpui::Context<Self>) {
+        let step = self.stepper_step;
+        if step == STEP_DONE {
+            return self.wizard_restart(window, cx);
+        }
+        match self.wizard.validate(step, cx) {
+            Ok(()) => {
+                self.wizard.error = None;
+                self.wizard.completed = self.wizard.completed.max(step + 1);
+                self.apply(Change::Stepper(step + 1), window, cx);
+            }
+            Err(key) => {
+                self.wizard.error = Some(key);
+                cx.notify();
+            }
+        }
+    }
+
+    pub(crate) fn wizard_back(&mut self, window: &mut Window, cx: &mut gpui::Context<Self>) {
+        if self.stepper_step > STEP_LOGIN {
+            self.wizard.error = None;
+            self.apply(Change::Stepper(self.stepper_step - 1), window, cx);
+        }
+    }
+
+    /// Clears the form and returns to the first step.
+    pub(crate) fn wizard_restart(&mut self, window: &mut Window, cx: &mut gpui::Context<Self>) {
+        for input in [
+            &self.wizard.username,
+            &self.wizard.password,
+            &self.wizard.code,
+        ] {
+            input.update(cx, |state, cx| state.set_value("", window, cx));
+        }
+        self.wizard.completed = STEP_LOGIN;
+        self.wizard.error = None;
+        self.apply(Change::Stepper(STEP_LOGIN), window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::*;
+    use crate::history::{Redo, Undo};
+    use crate::test_support::open_gallery;
+
+    #[gpui::test]
+    fn undo_returns_to_a_completed_step(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        gallery.update_in(cx, |this, window, cx| {
+            this.wizard.completed = STEP_VERIFY;
+            this.apply(Change::Stepper(STEP_VERIFY), window, cx);
+            this.wizard_back(window, cx);
+            assert_eq!(this.stepper_step, STEP_LOGIN);
+
+            this.undo(&Undo, window, cx);
+            assert_eq!(this.stepper_step, STEP_VERIFY);
+        });
+    }
+
+    #[gpui::test]
+    fn history_cannot_skip_steps_after_a_restart(cx: &mut TestAppContext) {
+        let (gallery, cx) = open_gallery(cx);
+        gallery.update_in(cx, |this, window, cx| {
+            this.wizard.completed = STEP_DONE;
+            this.apply(Change::Stepper(STEP_DONE), window, cx);
+            this.wizard_restart(window, cx);
+
+            // Undoing the restart would reopen the finished wizard, so that
+            // step is dropped and the one before it is undone instead.
+            this.undo(&Undo, window, cx);
+            assert_eq!(this.stepper_step, STEP_LOGIN);
+            assert!(!this.history.can_undo());
+
+            this.redo(&Redo, window, cx);
+            assert_eq!(this.stepper_step, STEP_LOGIN);
+            assert!(!this.history.can_redo());
+        });
+    }
+}